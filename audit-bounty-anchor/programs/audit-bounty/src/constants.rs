@@ -1,2 +1,15 @@
 pub const BOUNTY_SEED: &[u8] = b"bounty";
-pub const ESCROW_SEED: &[u8] = b"escrow"; 
\ No newline at end of file
+pub const ESCROW_SEED: &[u8] = b"escrow";
+pub const ACCEPTANCE_SEED: &[u8] = b"acceptance";
+pub const APPROVAL_SEED: &[u8] = b"approval";
+pub const EVENT_LOG_SEED: &[u8] = b"event_log";
+pub const METRICS_SEED: &[u8] = b"metrics";
+
+// Define the auto-release deadline in seconds (7 days)
+pub const AUTO_RELEASE_DEADLINE: i64 = 7 * 24 * 60 * 60;
+
+// Longest dispute window a creator may set (30 days)
+pub const MAX_DISPUTE_WINDOW: i64 = 30 * 24 * 60 * 60;
+
+// How long an unresolved dispute can hold up a release (7 days)
+pub const DISPUTE_RESOLUTION_PERIOD: i64 = 7 * 24 * 60 * 60;
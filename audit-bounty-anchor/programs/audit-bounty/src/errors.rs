@@ -25,4 +25,121 @@ pub enum BountyError {
     
     #[msg("Auto-release deadline has not been reached yet")]
     DeadlineNotReached,
-} 
\ No newline at end of file
+    
+    #[msg("Invalid bounty status transition")]
+    InvalidStatusTransition,
+    
+    #[msg("Holdback must be at most 10000 bps with a release time in the future")]
+    InvalidHoldback,
+    
+    #[msg("Bounty has no holdback to claim")]
+    NoHoldback,
+    
+    #[msg("Holdback release time has not been reached yet")]
+    HoldbackNotReleased,
+    
+    #[msg("Holdback has already been claimed")]
+    HoldbackAlreadyClaimed,
+    
+    #[msg("Recovery authority and destination must be set together")]
+    InvalidRecoveryConfig,
+    
+    #[msg("Only the recovery authority can perform this action")]
+    OnlyRecoveryAuthorityCanPerform,
+    
+    #[msg("Funds can only be recovered to the committed destination")]
+    InvalidRecoveryDestination,
+    
+    #[msg("Report does not match the required template")]
+    TemplateMismatch,
+    
+    #[msg("Dispute window cannot be negative")]
+    InvalidDisputeWindow,
+    
+    #[msg("Bounty has no pending release")]
+    NoPendingRelease,
+    
+    #[msg("Dispute window has not elapsed yet")]
+    DisputeWindowActive,
+    
+    #[msg("Dispute window has closed")]
+    DisputeWindowClosed,
+    
+    #[msg("Release is frozen by a dispute")]
+    ReleaseDisputed,
+    
+    #[msg("Creator cannot audit their own bounty")]
+    CannotAuditOwnBounty,
+    
+    #[msg("Holdback must be zero or cover the escrow's rent exemption")]
+    HoldbackBelowRentExemption,
+    
+    #[msg("Auditor has not accepted the bounty terms")]
+    TermsNotAccepted,
+    
+    #[msg("Payout cannot be redirected to the creator")]
+    InvalidPayoutRecipient,
+    
+    #[msg("Tags must be at most 5 known category ids")]
+    InvalidTags,
+    
+    #[msg("Forfeited funds must go to the bounty's forfeit destination")]
+    InvalidForfeitDestination,
+    
+    #[msg("Account does not match the bounty's auditor")]
+    InvalidAuditorAccount,
+    
+    #[msg("Approvers must be distinct, at most 5, with a threshold between 1 and their count")]
+    InvalidApprovalConfig,
+    
+    #[msg("Multisig bounties need their approval state account")]
+    ApprovalStateRequired,
+    
+    #[msg("Allowed schemes must be at most 4 non-empty prefixes of up to 16 bytes")]
+    InvalidSchemes,
+    
+    #[msg("Report URI scheme is not allowed for this bounty")]
+    ReportSchemeNotAllowed,
+    
+    #[msg("Event log account is required for this bounty")]
+    EventLogRequired,
+    
+    #[msg("Creator veto window must end before the auto-release deadline")]
+    InvalidVetoWindow,
+    
+    #[msg("Auto-release was vetoed by the creator")]
+    AutoReleaseVetoed,
+    
+    #[msg("Report hash must not be zero")]
+    InvalidReportHash,
+    
+    #[msg("Label is too long")]
+    InvalidLabel,
+    
+    #[msg("Bounty would exceed the program-wide locked value cap")]
+    TvlCapExceeded,
+    
+    #[msg("Release is not under dispute")]
+    NoActiveDispute,
+    
+    #[msg("Terms hash does not match the terms the bounty requires")]
+    TermsMismatch,
+    
+    #[msg("Not enough approvers signed for a multisig bounty")]
+    QuorumNotMet,
+    
+    #[msg("Payout cannot be reassigned on a multisig bounty")]
+    MultisigReassignNotAllowed,
+    
+    #[msg("Only the metrics authority can perform this action")]
+    OnlyMetricsAuthorityCanPerform,
+    
+    #[msg("Account is not a bounty in the pre-versioning layout")]
+    InvalidLegacyBounty,
+    
+    #[msg("Bounty does not accept submissions yet")]
+    SubmissionNotYetOpen,
+    
+    #[msg("Bounty can still pay out or refund")]
+    BountyNotSettled,
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, constants::*, errors::*, utils::*};
+
+#[derive(Accounts)]
+pub struct AcceptTerms<'info> {
+    #[account(mut)]
+    pub auditor: Signer<'info>,
+    
+    #[account(
+        seeds = [
+            BOUNTY_SEED, 
+            bounty.creator.as_ref(), 
+            &[bounty.nonce]
+        ],
+        bump = bounty.bump,
+        constraint = bounty.status == BountyStatus::Open @ BountyError::BountyNotOpen,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    
+    #[account(
+        init,
+        payer = auditor,
+        space = Acceptance::space(),
+        seeds = [
+            ACCEPTANCE_SEED,
+            bounty.key().as_ref(),
+            auditor.key().as_ref()
+        ],
+        bump
+    )]
+    pub acceptance: Account<'info, Acceptance>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AcceptTerms>, terms_hash: [u8; 32]) -> Result<()> {
+    // Only the bounty's current terms can be accepted; bounties without
+    // terms have nothing to accept
+    if ctx.accounts.bounty.required_terms_hash != Some(terms_hash) {
+        return Err(BountyError::TermsMismatch.into());
+    }
+    
+    let acceptance = &mut ctx.accounts.acceptance;
+    
+    // Record the auditor's consent
+    acceptance.bounty = ctx.accounts.bounty.key();
+    acceptance.auditor = ctx.accounts.auditor.key();
+    acceptance.terms_hash = terms_hash;
+    acceptance.accepted_at = now()?;
+    acceptance.bump = ctx.bumps.acceptance;
+    
+    Ok(())
+}
@@ -1,8 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::{state::*, constants::*, errors::*};
-
-// Define the deadline in seconds (7 days)
-const AUTO_RELEASE_DEADLINE: i64 = 7 * 24 * 60 * 60;
+use crate::{state::*, constants::*, errors::*, utils::*};
 
 #[derive(Accounts)]
 pub struct AutoRelease<'info> {
@@ -19,17 +16,13 @@ pub struct AutoRelease<'info> {
         ],
         bump = bounty.bump,
         constraint = bounty.status == BountyStatus::Submitted @ BountyError::BountyNotInReview,
-        // Check that deadline has passed
-        constraint = 
-            Clock::get().unwrap().unix_timestamp >= 
-            (bounty.created_at + AUTO_RELEASE_DEADLINE) @ BountyError::DeadlineNotReached,
     )]
     pub bounty: Account<'info, Bounty>,
     
     /// CHECK: This is the auditor who will receive the funds
     #[account(
         mut,
-        constraint = Some(auditor.key()) == bounty.auditor @ BountyError::InvalidEscrowAccount,
+        constraint = Some(auditor.key()) == bounty.payout_recipient() @ BountyError::InvalidAuditorAccount,
     )]
     pub auditor: AccountInfo<'info>,
     
@@ -39,12 +32,30 @@ pub struct AutoRelease<'info> {
             ESCROW_SEED,
             bounty.key().as_ref()
         ],
-        bump,
-        seeds::program = system_program.key()
+        bump = bounty.escrow_bump,
+        constraint = escrow.owner == &anchor_lang::system_program::ID @ BountyError::InvalidEscrowAccount,
     )]
     /// CHECK: This is the escrow PDA that holds the funds
     pub escrow: AccountInfo<'info>,
     
+    #[account(
+        mut,
+        seeds = [METRICS_SEED],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, GlobalMetrics>,
+    
+    // Required once the bounty was created with an event log
+    #[account(
+        mut,
+        seeds = [
+            EVENT_LOG_SEED,
+            bounty.key().as_ref()
+        ],
+        bump = event_log.bump,
+    )]
+    pub event_log: Option<Account<'info, EventLog>>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -53,34 +64,36 @@ pub fn handler(ctx: Context<AutoRelease>) -> Result<()> {
     let auditor = &ctx.accounts.auditor;
     let escrow = &ctx.accounts.escrow;
     
-    // Transfer funds from escrow to auditor
-    let amount = bounty.amount;
+    // Check that deadline has passed
+    let now = now()?;
+    if !bounty.auto_release_ready(now) {
+        return Err(BountyError::DeadlineNotReached.into());
+    }
     
-    let bounty_key = bounty.key();
-    let escrow_bump = ctx.bumps.escrow;
-    let escrow_seeds = &[
-        ESCROW_SEED,
-        bounty_key.as_ref(),
-        &[escrow_bump],
-    ];
+    // The creator rejected a report in time; only an explicit approval pays out
+    if bounty.auto_release_vetoed {
+        return Err(BountyError::AutoReleaseVetoed.into());
+    }
     
-    // Use invoke_signed to transfer funds from escrow PDA to auditor
-    anchor_lang::solana_program::program::invoke_signed(
-        &anchor_lang::solana_program::system_instruction::transfer(
-            escrow.key,
-            auditor.key,
-            amount,
-        ),
-        &[
-            escrow.clone(),
-            auditor.clone(),
-            ctx.accounts.system_program.to_account_info(),
-        ],
-        &[escrow_seeds],
+    // Transfer funds from escrow to auditor, keeping any holdback in escrow
+    let amount = bounty.release_payout(escrow.lamports());
+    
+    // Transfer funds from escrow PDA to auditor
+    transfer_from_escrow(
+        escrow,
+        auditor,
+        &ctx.accounts.system_program,
+        &bounty.key(),
+        bounty.escrow_bump,
+        amount,
     )?;
     
     // Update bounty status
-    bounty.status = BountyStatus::Approved;
+    bounty.approved_at = now;
+    bounty.set_status(BountyStatus::Approved)?;
+    
+    ctx.accounts.metrics.record_paid(amount, bounty.release_amount());
+    record_event(bounty, &mut ctx.accounts.event_log, EventKind::AutoReleased, ctx.accounts.payer.key(), amount)?;
     
     Ok(())
 } 
\ No newline at end of file
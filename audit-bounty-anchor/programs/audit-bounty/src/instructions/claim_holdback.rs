@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, constants::*, errors::*, utils::*};
+
+#[derive(Accounts)]
+pub struct ClaimHoldback<'info> {
+    #[account(
+        mut,
+        constraint = Some(auditor.key()) == bounty.payout_recipient() @ BountyError::InvalidAuditorAccount,
+    )]
+    pub auditor: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [
+            BOUNTY_SEED, 
+            bounty.creator.as_ref(), 
+            &[bounty.nonce]
+        ],
+        bump = bounty.bump,
+        constraint = bounty.status == BountyStatus::Approved @ BountyError::BountyNotCompleted,
+        constraint = !bounty.release_pending @ BountyError::BountyNotCompleted,
+        constraint = bounty.holdback_bps > 0 @ BountyError::NoHoldback,
+        constraint = !bounty.holdback_claimed @ BountyError::HoldbackAlreadyClaimed,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    
+    #[account(
+        mut,
+        seeds = [
+            ESCROW_SEED,
+            bounty.key().as_ref()
+        ],
+        bump = bounty.escrow_bump,
+        constraint = escrow.owner == &anchor_lang::system_program::ID @ BountyError::InvalidEscrowAccount,
+    )]
+    /// CHECK: This is the escrow PDA that holds the funds
+    pub escrow: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [METRICS_SEED],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, GlobalMetrics>,
+    
+    // Required once the bounty was created with an event log
+    #[account(
+        mut,
+        seeds = [
+            EVENT_LOG_SEED,
+            bounty.key().as_ref()
+        ],
+        bump = event_log.bump,
+    )]
+    pub event_log: Option<Account<'info, EventLog>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimHoldback>) -> Result<()> {
+    let bounty = &mut ctx.accounts.bounty;
+    let auditor = &ctx.accounts.auditor;
+    let escrow = &ctx.accounts.escrow;
+    
+    // Holdback stays locked until the remediation period has passed
+    if !bounty.holdback_released(now()?) {
+        return Err(BountyError::HoldbackNotReleased.into());
+    }
+    
+    // Mark as claimed before moving funds. Sweep the whole escrow so
+    // anything sent to it after the release does not strand below rent.
+    let amount = escrow.lamports();
+    bounty.holdback_claimed = true;
+    
+    // Transfer the holdback from escrow PDA to auditor
+    transfer_from_escrow(
+        escrow,
+        &auditor.to_account_info(),
+        &ctx.accounts.system_program,
+        &bounty.key(),
+        bounty.escrow_bump,
+        amount,
+    )?;
+    
+    ctx.accounts.metrics.record_paid(amount, bounty.holdback_amount());
+    record_event(bounty, &mut ctx.accounts.event_log, EventKind::HoldbackClaimed, auditor.key(), amount)?;
+    
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, constants::*, errors::*};
+
+#[derive(Accounts)]
+pub struct CloseAcceptance<'info> {
+    #[account(
+        seeds = [
+            BOUNTY_SEED, 
+            bounty.creator.as_ref(), 
+            &[bounty.nonce]
+        ],
+        bump = bounty.bump,
+        constraint = bounty.settled() @ BountyError::BountyNotSettled,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    
+    // The auditor paid the rent, so it goes back to them whoever closes
+    #[account(
+        mut,
+        close = auditor,
+        seeds = [
+            ACCEPTANCE_SEED,
+            bounty.key().as_ref(),
+            auditor.key().as_ref()
+        ],
+        bump = acceptance.bump,
+    )]
+    pub acceptance: Account<'info, Acceptance>,
+    
+    #[account(mut)]
+    pub auditor: SystemAccount<'info>,
+}
+
+pub fn handler(_ctx: Context<CloseAcceptance>) -> Result<()> {
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::{state::*, constants::*, errors::*};
+use crate::{state::*, constants::*, errors::*, utils::*};
 
 #[derive(Accounts)]
 pub struct CancelBounty<'info> {
@@ -21,54 +21,82 @@ pub struct CancelBounty<'info> {
     )]
     pub bounty: Account<'info, Bounty>,
     
+    /// CHECK: Must match bounty.forfeit_destination when one is set
+    #[account(
+        mut,
+        constraint = Some(forfeit_destination.key()) == bounty.forfeit_destination @ BountyError::InvalidForfeitDestination,
+    )]
+    pub forfeit_destination: Option<UncheckedAccount<'info>>,
+    
     #[account(
         mut,
         seeds = [
             ESCROW_SEED,
             bounty.key().as_ref()
         ],
-        bump,
-        seeds::program = system_program.key()
+        bump = bounty.escrow_bump,
+        constraint = escrow.owner == &anchor_lang::system_program::ID @ BountyError::InvalidEscrowAccount,
     )]
     /// CHECK: This is the escrow PDA that holds the funds
     pub escrow: AccountInfo<'info>,
     
+    #[account(
+        mut,
+        seeds = [METRICS_SEED],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, GlobalMetrics>,
+    
+    // Required once the bounty was created with an event log
+    #[account(
+        mut,
+        seeds = [
+            EVENT_LOG_SEED,
+            bounty.key().as_ref()
+        ],
+        bump = event_log.bump,
+    )]
+    pub event_log: Option<Account<'info, EventLog>>,
+    
     pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<CancelBounty>) -> Result<()> {
     let bounty = &mut ctx.accounts.bounty;
-    let creator = &ctx.accounts.creator;
     let escrow = &ctx.accounts.escrow;
     
-    // Transfer funds from escrow back to creator
-    let amount = bounty.amount;
+    // A multisig creator's key cannot act alone; approvers co-sign as
+    // remaining accounts
+    if bounty.creator_is_multisig && !bounty.has_quorum(&remaining_signers(ctx.remaining_accounts)) {
+        return Err(BountyError::QuorumNotMet.into());
+    }
     
-    let bounty_key = bounty.key();
-    let escrow_bump = ctx.bumps.escrow;
-    let escrow_seeds = &[
-        ESCROW_SEED,
-        bounty_key.as_ref(),
-        &[escrow_bump],
-    ];
+    // Funds go to the forfeit destination fixed at creation, else the creator
+    let recipient = match (bounty.forfeit_destination, &ctx.accounts.forfeit_destination) {
+        (Some(_), Some(destination)) => destination.to_account_info(),
+        (Some(_), None) => return Err(BountyError::InvalidForfeitDestination.into()),
+        (None, _) => ctx.accounts.creator.to_account_info(),
+    };
     
-    // Use invoke_signed to transfer funds from escrow PDA back to creator
-    anchor_lang::solana_program::program::invoke_signed(
-        &anchor_lang::solana_program::system_instruction::transfer(
-            escrow.key,
-            creator.key,
-            amount,
-        ),
-        &[
-            escrow.clone(),
-            creator.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-        ],
-        &[escrow_seeds],
+    // Return the whole escrow balance so donations are not stranded. The
+    // escrow is a data-less system account, so draining it closes it.
+    let amount = escrow.lamports();
+    
+    // Transfer funds from escrow PDA to the recipient
+    transfer_from_escrow(
+        escrow,
+        &recipient,
+        &ctx.accounts.system_program,
+        &bounty.key(),
+        bounty.escrow_bump,
+        amount,
     )?;
     
     // Update bounty status
-    bounty.status = BountyStatus::Cancelled;
+    bounty.set_status(BountyStatus::Cancelled)?;
+    
+    ctx.accounts.metrics.record_refunded(amount, bounty.amount);
+    record_event(bounty, &mut ctx.accounts.event_log, EventKind::Cancelled, ctx.accounts.creator.key(), amount)?;
     
     Ok(())
 } 
\ No newline at end of file
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, constants::*, errors::*};
+
+#[derive(Accounts)]
+pub struct CloseBountyAccounts<'info> {
+    #[account(
+        mut,
+        seeds = [
+            BOUNTY_SEED, 
+            bounty.creator.as_ref(), 
+            &[bounty.nonce]
+        ],
+        bump = bounty.bump,
+        constraint = bounty.settled() @ BountyError::BountyNotSettled,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    
+    // Paid the rent for both accounts at creation, so it gets it back
+    // whoever closes them
+    #[account(
+        mut,
+        constraint = creator.key() == bounty.creator @ BountyError::OnlyCreatorCanPerform,
+    )]
+    pub creator: SystemAccount<'info>,
+    
+    #[account(
+        mut,
+        close = creator,
+        seeds = [
+            APPROVAL_SEED,
+            bounty.key().as_ref()
+        ],
+        bump = approval_state.bump,
+    )]
+    pub approval_state: Option<Account<'info, ApprovalState>>,
+    
+    #[account(
+        mut,
+        close = creator,
+        seeds = [
+            EVENT_LOG_SEED,
+            bounty.key().as_ref()
+        ],
+        bump = event_log.bump,
+    )]
+    pub event_log: Option<Account<'info, EventLog>>,
+}
+
+pub fn handler(ctx: Context<CloseBountyAccounts>) -> Result<()> {
+    // The bounty no longer has a log to record into
+    if ctx.accounts.event_log.is_some() {
+        ctx.accounts.bounty.event_log_enabled = false;
+    }
+    
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::{state::*, constants::*};
+use crate::{state::*, constants::*, errors::*};
 
 #[derive(Accounts)]
 #[instruction(amount: u64, nonce: u8)]
@@ -27,22 +27,129 @@ pub struct CreateBounty<'info> {
             bounty.key().as_ref()
         ],
         bump,
-        seeds::program = system_program.key()
+        // Derived under this program, held by the system program so we can sign transfers
+        constraint = escrow.owner == &anchor_lang::system_program::ID @ BountyError::InvalidEscrowAccount,
     )]
     /// CHECK: This is the escrow account for the bounty
     pub escrow: AccountInfo<'info>,
     
+    #[account(
+        mut,
+        seeds = [METRICS_SEED],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, GlobalMetrics>,
+    
+    // Only created for multisig creators
+    #[account(
+        init,
+        payer = creator,
+        space = ApprovalState::space(),
+        seeds = [
+            APPROVAL_SEED,
+            bounty.key().as_ref()
+        ],
+        bump
+    )]
+    pub approval_state: Option<Account<'info, ApprovalState>>,
+    
+    // Optional on-chain history, see EventLog
+    #[account(
+        init,
+        payer = creator,
+        space = EventLog::space(),
+        seeds = [
+            EVENT_LOG_SEED,
+            bounty.key().as_ref()
+        ],
+        bump
+    )]
+    pub event_log: Option<Account<'info, EventLog>>,
+    
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<CreateBounty>, amount: u64, nonce: u8) -> Result<()> {
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<CreateBounty>,
+    amount: u64,
+    nonce: u8,
+    holdback_bps: u16,
+    holdback_release_at: i64,
+    recovery_authority: Option<Pubkey>,
+    recovery_destination: Option<Pubkey>,
+    required_template_hash: Option<[u8; 32]>,
+    dispute_window: i64,
+    required_terms_hash: Option<[u8; 32]>,
+    tags: Vec<u8>,
+    forfeit_destination: Option<Pubkey>,
+    approvers: Vec<Pubkey>,
+    approval_threshold: u8,
+    allowed_schemes: Vec<String>,
+    creator_veto_until: i64,
+    label: Option<String>,
+    submission_start: i64,
+) -> Result<()> {
     let bounty = &mut ctx.accounts.bounty;
     let creator = &ctx.accounts.creator;
     let escrow = &ctx.accounts.escrow;
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    
+    // Validate holdback settings
+    if holdback_bps > Bounty::MAX_BPS || (holdback_bps > 0 && holdback_release_at <= now) {
+        return Err(BountyError::InvalidHoldback.into());
+    }
+    
+    // The escrow is a data-less system account. Approval pays out all of it
+    // except the holdback, so without one the escrow is drained and closed;
+    // a holdback must stay rent-exempt on its own or the release transfer
+    // would be rejected.
+    let holdback = Bounty::holdback_for(amount, holdback_bps);
+    if holdback > 0 && holdback < Rent::get()?.minimum_balance(0) {
+        return Err(BountyError::HoldbackBelowRentExemption.into());
+    }
+    
+    ctx.accounts.metrics.lock(amount)?;
+    
+    // Recovery is all-or-nothing so funds can never be recovered to an open-ended address
+    if recovery_authority.is_some() != recovery_destination.is_some() {
+        return Err(BountyError::InvalidRecoveryConfig.into());
+    }
+    
+    if !Bounty::validate_tags(&tags) {
+        return Err(BountyError::InvalidTags.into());
+    }
     
-    // Set bumps for PDAs
-    let bump = bounty.bump;
-    let _escrow_bump = ctx.bumps.escrow;
+    // Multisig creators need an approval state to collect approvals in
+    let creator_is_multisig = !approvers.is_empty();
+    if !Bounty::validate_approvers(&approvers, approval_threshold)
+        || creator_is_multisig != ctx.accounts.approval_state.is_some()
+    {
+        return Err(BountyError::InvalidApprovalConfig.into());
+    }
+    
+    if !Bounty::validate_schemes(&allowed_schemes) {
+        return Err(BountyError::InvalidSchemes.into());
+    }
+    
+    if !Bounty::validate_label(&label) {
+        return Err(BountyError::InvalidLabel.into());
+    }
+    
+    if !(0..=MAX_DISPUTE_WINDOW).contains(&dispute_window) {
+        return Err(BountyError::InvalidDisputeWindow.into());
+    }
+    
+    // The veto must close before auto-release opens; 0 disables it
+    if creator_veto_until != 0
+        && (creator_veto_until <= now || creator_veto_until >= now + AUTO_RELEASE_DEADLINE)
+    {
+        return Err(BountyError::InvalidVetoWindow.into());
+    }
+    
+    // Set bump for the bounty PDA
+    let bump = ctx.bumps.bounty;
     
     // Transfer funds from creator to escrow account
     let transfer_ix = anchor_lang::solana_program::system_instruction::transfer(
@@ -51,6 +158,9 @@ pub fn handler(ctx: Context<CreateBounty>, amount: u64, nonce: u8) -> Result<()>
         amount,
     );
     
+    // Anchor checks the Program type; this catches the struct being loosened later
+    debug_assert_eq!(ctx.accounts.system_program.key(), anchor_lang::system_program::ID);
+    
     anchor_lang::solana_program::program::invoke(
         &transfer_ix,
         &[
@@ -61,14 +171,61 @@ pub fn handler(ctx: Context<CreateBounty>, amount: u64, nonce: u8) -> Result<()>
     )?;
     
     // Initialize bounty account
+    bounty.version = Bounty::VERSION;
     bounty.creator = *creator.key;
     bounty.auditor = None;
     bounty.amount = amount;
     bounty.status = BountyStatus::Open;
+    bounty.tags = tags;
     bounty.report_uri = None;
-    bounty.created_at = Clock::get()?.unix_timestamp;
+    bounty.created_at = now;
+    bounty.created_slot = clock.slot;
     bounty.nonce = nonce;
     bounty.bump = bump;
+    bounty.escrow_bump = ctx.bumps.escrow;
+    bounty.holdback_bps = holdback_bps;
+    bounty.holdback_release_at = holdback_release_at;
+    bounty.holdback_claimed = false;
+    bounty.recovery_authority = recovery_authority;
+    bounty.recovery_destination = recovery_destination;
+    bounty.required_template_hash = required_template_hash;
+    bounty.report_template_hash = None;
+    bounty.dispute_window = dispute_window;
+    bounty.approved_at = 0;
+    bounty.release_pending = false;
+    bounty.disputed = false;
+    bounty.disputed_at = 0;
+    bounty.disputed_by = None;
+    bounty.required_terms_hash = required_terms_hash;
+    bounty.payout_override = None;
+    bounty.forfeit_destination = forfeit_destination;
+    bounty.creator_is_multisig = creator_is_multisig;
+    bounty.approvers = approvers;
+    bounty.approval_threshold = approval_threshold;
+    bounty.allowed_schemes = allowed_schemes;
+    bounty.event_log_enabled = ctx.accounts.event_log.is_some();
+    bounty.creator_veto_until = creator_veto_until;
+    bounty.auto_release_vetoed = false;
+    bounty.report_hash = None;
+    bounty.label = label;
+    bounty.reviewed_at = None;
+    bounty.submission_start = submission_start;
+    
+    if let Some(approval_state) = ctx.accounts.approval_state.as_mut() {
+        approval_state.bounty = bounty.key();
+        approval_state.approvals = Vec::new();
+        approval_state.bump = ctx.bumps.approval_state;
+    }
+    
+    if let Some(event_log) = ctx.accounts.event_log.as_mut() {
+        event_log.bounty = bounty.key();
+        event_log.total = 0;
+        event_log.records = Vec::new();
+        event_log.bump = ctx.bumps.event_log;
+    }
+    
+    ctx.accounts.metrics.record_created(amount);
+    record_event(bounty, &mut ctx.accounts.event_log, EventKind::Created, creator.key(), amount)?;
     
     Ok(())
 } 
\ No newline at end of file
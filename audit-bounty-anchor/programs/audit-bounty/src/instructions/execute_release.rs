@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, constants::*, errors::*, utils::*};
+
+#[derive(Accounts)]
+pub struct ExecuteRelease<'info> {
+    /// CHECK: Can be anyone triggering the release once the dispute window has passed
+    pub payer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [
+            BOUNTY_SEED, 
+            bounty.creator.as_ref(), 
+            &[bounty.nonce]
+        ],
+        bump = bounty.bump,
+        constraint = bounty.status == BountyStatus::Approved @ BountyError::BountyNotCompleted,
+        constraint = bounty.release_pending @ BountyError::NoPendingRelease,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    
+    /// CHECK: This is the auditor who will receive the funds
+    #[account(
+        mut,
+        constraint = Some(auditor.key()) == bounty.payout_recipient() @ BountyError::InvalidAuditorAccount,
+    )]
+    pub auditor: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [
+            ESCROW_SEED,
+            bounty.key().as_ref()
+        ],
+        bump = bounty.escrow_bump,
+        constraint = escrow.owner == &anchor_lang::system_program::ID @ BountyError::InvalidEscrowAccount,
+    )]
+    /// CHECK: This is the escrow PDA that holds the funds
+    pub escrow: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [METRICS_SEED],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, GlobalMetrics>,
+    
+    // Required once the bounty was created with an event log
+    #[account(
+        mut,
+        seeds = [
+            EVENT_LOG_SEED,
+            bounty.key().as_ref()
+        ],
+        bump = event_log.bump,
+    )]
+    pub event_log: Option<Account<'info, EventLog>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ExecuteRelease>) -> Result<()> {
+    let bounty = &mut ctx.accounts.bounty;
+    let auditor = &ctx.accounts.auditor;
+    let escrow = &ctx.accounts.escrow;
+    
+    // Funds only move once nobody can dispute the approval any more
+    let now = now()?;
+    if now < bounty.dispute_window_ends_at() {
+        return Err(BountyError::DisputeWindowActive.into());
+    }
+    
+    // Nobody dismissed or upheld the dispute in time: the approval stands
+    if bounty.release_frozen(now) {
+        return Err(BountyError::ReleaseDisputed.into());
+    }
+    
+    bounty.release_pending = false;
+    
+    // Transfer funds from escrow to auditor, keeping any holdback in escrow
+    let amount = bounty.release_payout(escrow.lamports());
+    
+    // Transfer funds from escrow PDA to auditor
+    transfer_from_escrow(
+        escrow,
+        auditor,
+        &ctx.accounts.system_program,
+        &bounty.key(),
+        bounty.escrow_bump,
+        amount,
+    )?;
+    
+    ctx.accounts.metrics.record_paid(amount, bounty.release_amount());
+    record_event(bounty, &mut ctx.accounts.event_log, EventKind::Released, ctx.accounts.payer.key(), amount)?;
+    
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, constants::*};
+
+#[derive(Accounts)]
+pub struct GetMetrics<'info> {
+    #[account(
+        seeds = [METRICS_SEED],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, GlobalMetrics>,
+}
+
+// Read-only: the totals come back as return data, so clients can simulate
+// this instead of decoding the account themselves
+pub fn handler(ctx: Context<GetMetrics>) -> Result<MetricsSnapshot> {
+    Ok(ctx.accounts.metrics.snapshot())
+}
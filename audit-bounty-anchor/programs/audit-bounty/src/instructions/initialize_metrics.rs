@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, constants::*, errors::*, program::AuditBounty};
+
+#[derive(Accounts)]
+pub struct InitializeMetrics<'info> {
+    // Must be the program's upgrade authority, so nobody can front-run the
+    // deployment and pick the cap
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = GlobalMetrics::space(),
+        seeds = [METRICS_SEED],
+        bump
+    )]
+    pub metrics: Account<'info, GlobalMetrics>,
+    
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ BountyError::OnlyMetricsAuthorityCanPerform,
+    )]
+    pub program: Program<'info, AuditBounty>,
+    
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(payer.key()) @ BountyError::OnlyMetricsAuthorityCanPerform,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeMetrics>, max_tvl: u64) -> Result<()> {
+    let metrics = &mut ctx.accounts.metrics;
+    
+    metrics.total_created = 0;
+    metrics.total_paid = 0;
+    metrics.total_refunded = 0;
+    metrics.bump = ctx.bumps.metrics;
+    metrics.total_locked = 0;
+    metrics.max_tvl = max_tvl;
+    metrics.authority = ctx.accounts.payer.key();
+    metrics.total_topped_up = 0;
+    
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, constants::*, errors::*, utils::*};
+
+#[derive(Accounts)]
+pub struct MarkReportReviewed<'info> {
+    #[account(
+        constraint = creator.key() == bounty.creator @ BountyError::OnlyCreatorCanPerform
+    )]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [
+            BOUNTY_SEED, 
+            bounty.creator.as_ref(), 
+            &[bounty.nonce]
+        ],
+        bump = bounty.bump,
+        constraint = bounty.status == BountyStatus::Submitted @ BountyError::BountyNotInReview,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    
+    // Required once the bounty was created with an event log
+    #[account(
+        mut,
+        seeds = [
+            EVENT_LOG_SEED,
+            bounty.key().as_ref()
+        ],
+        bump = event_log.bump,
+    )]
+    pub event_log: Option<Account<'info, EventLog>>,
+}
+
+#[event]
+pub struct ReportReviewed {
+    pub bounty: Pubkey,
+    pub auditor: Option<Pubkey>,
+    pub reviewed_at: i64,
+}
+
+pub fn handler(ctx: Context<MarkReportReviewed>) -> Result<()> {
+    let bounty = &mut ctx.accounts.bounty;
+    
+    // Only tells the auditor the report was seen; approval is still pending
+    let now = now()?;
+    bounty.reviewed_at = Some(now);
+    
+    emit!(ReportReviewed {
+        bounty: bounty.key(),
+        auditor: bounty.auditor,
+        reviewed_at: now,
+    });
+    
+    record_event(bounty, &mut ctx.accounts.event_log, EventKind::Reviewed, ctx.accounts.creator.key(), 0)?;
+    
+    Ok(())
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::{state::*, constants::*, errors::*};
+
+#[derive(Accounts)]
+pub struct MigrateBounty<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// CHECK: A bounty in the pre-versioning layout, which Account<Bounty>
+    /// cannot load. The handler checks its discriminator, size and address.
+    #[account(
+        mut,
+        owner = crate::ID @ BountyError::InvalidLegacyBounty,
+    )]
+    pub bounty: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [
+            ESCROW_SEED,
+            bounty.key().as_ref()
+        ],
+        bump,
+    )]
+    /// CHECK: The escrow PDA the migrated bounty will use
+    pub escrow: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [METRICS_SEED],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, GlobalMetrics>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateBounty>) -> Result<()> {
+    let bounty_info = ctx.accounts.bounty.to_account_info();
+    let creator = &ctx.accounts.creator;
+    
+    let legacy = {
+        let data = bounty_info.try_borrow_data()?;
+        if data.len() != LegacyBounty::SPACE || data[..8] != Bounty::DISCRIMINATOR {
+            return Err(BountyError::InvalidLegacyBounty.into());
+        }
+        LegacyBounty::deserialize(&mut &data[8..])?
+    };
+    
+    if legacy.creator != creator.key() {
+        return Err(BountyError::OnlyCreatorCanPerform.into());
+    }
+    let expected = Pubkey::create_program_address(
+        &[BOUNTY_SEED, legacy.creator.as_ref(), &[legacy.nonce], &[legacy.bump]],
+        &crate::ID,
+    )
+    .map_err(|_| BountyError::InvalidLegacyBounty)?;
+    if expected != bounty_info.key() {
+        return Err(BountyError::InvalidLegacyBounty.into());
+    }
+    
+    // The first release derived escrows under the system program, so no
+    // program can sign for them and their lamports cannot be moved. A
+    // bounty that can still pay out is re-funded into its new escrow.
+    let amount = legacy.amount;
+    let refund_escrow = matches!(legacy.status, BountyStatus::Open | BountyStatus::Submitted);
+    if refund_escrow {
+        ctx.accounts.metrics.lock(amount)?;
+        anchor_lang::solana_program::program::invoke(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                creator.key,
+                ctx.accounts.escrow.key,
+                amount,
+            ),
+            &[
+                creator.to_account_info(),
+                ctx.accounts.escrow.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+        ctx.accounts.metrics.record_created(amount);
+    }
+    
+    // Cover the rent for the larger layout, then grow the account
+    let shortfall = Rent::get()?
+        .minimum_balance(Bounty::space())
+        .saturating_sub(bounty_info.lamports());
+    if shortfall > 0 {
+        anchor_lang::solana_program::program::invoke(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                creator.key,
+                bounty_info.key,
+                shortfall,
+            ),
+            &[
+                creator.to_account_info(),
+                bounty_info.clone(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+    }
+    bounty_info.realloc(Bounty::space(), true)?;
+    
+    let bounty = Bounty::from_legacy(legacy, ctx.bumps.escrow);
+    bounty.try_serialize(&mut &mut bounty_info.try_borrow_mut_data()?[..])?;
+    
+    Ok(())
+}
@@ -1,14 +1,47 @@
+#![allow(ambiguous_glob_reexports)]
+
 pub mod create_bounty;
 pub mod submit_report;
 pub mod release_funds;
 pub mod reject_report;
 pub mod close_bounty;
 pub mod auto_release;
+pub mod claim_holdback;
+pub mod recover_bounty;
+pub mod execute_release;
+pub mod open_dispute;
+pub mod accept_terms;
+pub mod reassign_payout;
+pub mod set_label;
+pub mod initialize_metrics;
+pub mod mark_report_reviewed;
+pub mod resolve_dispute;
+pub mod set_max_tvl;
+pub mod migrate_bounty;
+pub mod get_metrics;
+pub mod close_acceptance;
+pub mod close_bounty_accounts;
 
-// Re-export structs for cleaner imports
-pub use create_bounty::CreateBounty;
-pub use submit_report::SubmitReport;
-pub use release_funds::ApproveAndRelease;
-pub use reject_report::RejectReport;
-pub use close_bounty::CancelBounty;
-pub use auto_release::AutoRelease; 
\ No newline at end of file
+// Re-export account structs (and the client modules Anchor generates for them).
+// Each module's `handler` is always called by its full path from lib.rs.
+pub use create_bounty::*;
+pub use submit_report::*;
+pub use release_funds::*;
+pub use reject_report::*;
+pub use close_bounty::*;
+pub use auto_release::*;
+pub use claim_holdback::*;
+pub use recover_bounty::*;
+pub use execute_release::*;
+pub use open_dispute::*;
+pub use accept_terms::*;
+pub use reassign_payout::*;
+pub use set_label::*;
+pub use initialize_metrics::*;
+pub use mark_report_reviewed::*;
+pub use resolve_dispute::*;
+pub use set_max_tvl::*;
+pub use migrate_bounty::*;
+pub use get_metrics::*;
+pub use close_acceptance::*;
+pub use close_bounty_accounts::*;
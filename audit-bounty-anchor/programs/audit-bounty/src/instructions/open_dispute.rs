@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, constants::*, errors::*, utils::*};
+
+#[derive(Accounts)]
+pub struct OpenDispute<'info> {
+    /// CHECK: Anyone may contest an approval during the dispute window
+    pub disputer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [
+            BOUNTY_SEED, 
+            bounty.creator.as_ref(), 
+            &[bounty.nonce]
+        ],
+        bump = bounty.bump,
+        constraint = bounty.status == BountyStatus::Approved @ BountyError::BountyNotCompleted,
+        constraint = bounty.release_pending @ BountyError::NoPendingRelease,
+        constraint = !bounty.disputed @ BountyError::ReleaseDisputed,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    
+    // Required once the bounty was created with an event log
+    #[account(
+        mut,
+        seeds = [
+            EVENT_LOG_SEED,
+            bounty.key().as_ref()
+        ],
+        bump = event_log.bump,
+    )]
+    pub event_log: Option<Account<'info, EventLog>>,
+}
+
+pub fn handler(ctx: Context<OpenDispute>) -> Result<()> {
+    let bounty = &mut ctx.accounts.bounty;
+    
+    let now = now()?;
+    if now >= bounty.dispute_window_ends_at() {
+        return Err(BountyError::DisputeWindowClosed.into());
+    }
+    
+    // Freeze the pending release until the dispute is resolved or expires
+    bounty.disputed = true;
+    bounty.disputed_at = now;
+    bounty.disputed_by = Some(ctx.accounts.disputer.key());
+    
+    record_event(bounty, &mut ctx.accounts.event_log, EventKind::Disputed, ctx.accounts.disputer.key(), 0)?;
+    
+    Ok(())
+}
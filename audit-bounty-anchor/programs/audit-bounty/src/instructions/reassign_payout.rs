@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, constants::*, errors::*};
+
+#[derive(Accounts)]
+pub struct ReassignPayout<'info> {
+    #[account(
+        constraint = creator.key() == bounty.creator @ BountyError::OnlyCreatorCanPerform
+    )]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [
+            BOUNTY_SEED, 
+            bounty.creator.as_ref(), 
+            &[bounty.nonce]
+        ],
+        bump = bounty.bump,
+        constraint = bounty.status == BountyStatus::Submitted @ BountyError::BountyNotInReview,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    
+    // Required once the bounty was created with an event log
+    #[account(
+        mut,
+        seeds = [
+            EVENT_LOG_SEED,
+            bounty.key().as_ref()
+        ],
+        bump = event_log.bump,
+    )]
+    pub event_log: Option<Account<'info, EventLog>>,
+}
+
+pub fn handler(ctx: Context<ReassignPayout>, new_recipient: Pubkey) -> Result<()> {
+    let bounty = &mut ctx.accounts.bounty;
+    
+    // Redirecting the payout would let one key of a multisig creator
+    // decide where the funds go
+    if bounty.creator_is_multisig {
+        return Err(BountyError::MultisigReassignNotAllowed.into());
+    }
+    
+    // Recovery for an auditor who lost their key, agreed off-chain. It must
+    // never let the creator pay themselves.
+    if new_recipient == bounty.creator {
+        return Err(BountyError::InvalidPayoutRecipient.into());
+    }
+    
+    bounty.payout_override = Some(new_recipient);
+    
+    record_event(bounty, &mut ctx.accounts.event_log, EventKind::PayoutReassigned, ctx.accounts.creator.key(), 0)?;
+    
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, constants::*, errors::*, utils::*};
+
+#[derive(Accounts)]
+pub struct RecoverBounty<'info> {
+    #[account(
+        constraint = Some(recovery_authority.key()) == bounty.recovery_authority @ BountyError::OnlyRecoveryAuthorityCanPerform
+    )]
+    pub recovery_authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [
+            BOUNTY_SEED, 
+            bounty.creator.as_ref(), 
+            &[bounty.nonce]
+        ],
+        bump = bounty.bump,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    
+    /// CHECK: This is the destination committed at creation
+    #[account(
+        mut,
+        constraint = Some(recovery_destination.key()) == bounty.recovery_destination @ BountyError::InvalidRecoveryDestination,
+    )]
+    pub recovery_destination: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [
+            ESCROW_SEED,
+            bounty.key().as_ref()
+        ],
+        bump = bounty.escrow_bump,
+        constraint = escrow.owner == &anchor_lang::system_program::ID @ BountyError::InvalidEscrowAccount,
+    )]
+    /// CHECK: This is the escrow PDA that holds the funds
+    pub escrow: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [METRICS_SEED],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, GlobalMetrics>,
+    
+    // Required once the bounty was created with an event log
+    #[account(
+        mut,
+        seeds = [
+            EVENT_LOG_SEED,
+            bounty.key().as_ref()
+        ],
+        bump = event_log.bump,
+    )]
+    pub event_log: Option<Account<'info, EventLog>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RecoverBounty>) -> Result<()> {
+    let bounty = &mut ctx.accounts.bounty;
+    let recovery_destination = &ctx.accounts.recovery_destination;
+    let escrow = &ctx.accounts.escrow;
+    
+    if !bounty.recoverable(now()?) {
+        return Err(BountyError::BountyNotOpen.into());
+    }
+    
+    // Transfer the whole escrow balance to the committed recovery destination
+    let amount = escrow.lamports();
+    
+    // Transfer funds from escrow PDA to the recovery destination
+    transfer_from_escrow(
+        escrow,
+        recovery_destination,
+        &ctx.accounts.system_program,
+        &bounty.key(),
+        bounty.escrow_bump,
+        amount,
+    )?;
+    
+    // Recovery is a cancel that bypasses the creator, and on a disputed
+    // release it upholds the dispute
+    bounty.release_pending = false;
+    bounty.set_status(BountyStatus::Cancelled)?;
+    
+    ctx.accounts.metrics.record_refunded(amount, bounty.amount);
+    record_event(bounty, &mut ctx.accounts.event_log, EventKind::Recovered, ctx.accounts.recovery_authority.key(), amount)?;
+    
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::{state::*, constants::*, errors::*};
+use crate::{state::*, constants::*, errors::*, utils::*};
 
 #[derive(Accounts)]
 pub struct RejectReport<'info> {
@@ -19,15 +19,59 @@ pub struct RejectReport<'info> {
         constraint = bounty.status == BountyStatus::Submitted @ BountyError::BountyNotInReview,
     )]
     pub bounty: Account<'info, Bounty>,
+    
+    // Required for multisig bounties so approvals don't carry over to the next report
+    #[account(
+        mut,
+        seeds = [
+            APPROVAL_SEED,
+            bounty.key().as_ref()
+        ],
+        bump = approval_state.bump,
+    )]
+    pub approval_state: Option<Account<'info, ApprovalState>>,
+    
+    // Required once the bounty was created with an event log
+    #[account(
+        mut,
+        seeds = [
+            EVENT_LOG_SEED,
+            bounty.key().as_ref()
+        ],
+        bump = event_log.bump,
+    )]
+    pub event_log: Option<Account<'info, EventLog>>,
 }
 
 pub fn handler(ctx: Context<RejectReport>) -> Result<()> {
     let bounty = &mut ctx.accounts.bounty;
     
+    // A multisig creator's key cannot act alone; approvers co-sign as
+    // remaining accounts
+    if bounty.creator_is_multisig && !bounty.has_quorum(&remaining_signers(ctx.remaining_accounts)) {
+        return Err(BountyError::QuorumNotMet.into());
+    }
+    
+    if bounty.creator_is_multisig {
+        let approval_state = ctx.accounts.approval_state.as_mut()
+            .ok_or(BountyError::ApprovalStateRequired)?;
+        approval_state.approvals.clear();
+    }
+    
+    if bounty.in_veto_window(now()?) {
+        bounty.auto_release_vetoed = true;
+    }
+    
     // Reset bounty
     bounty.auditor = None;
     bounty.report_uri = None;
-    bounty.status = BountyStatus::Open;
+    bounty.report_template_hash = None;
+    bounty.report_hash = None;
+    bounty.reviewed_at = None;
+    bounty.payout_override = None;
+    bounty.set_status(BountyStatus::Open)?;
+    
+    record_event(bounty, &mut ctx.accounts.event_log, EventKind::Rejected, ctx.accounts.creator.key(), 0)?;
     
     Ok(())
 } 
\ No newline at end of file
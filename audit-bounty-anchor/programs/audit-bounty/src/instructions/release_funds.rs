@@ -1,10 +1,11 @@
 use anchor_lang::prelude::*;
-use crate::{state::*, constants::*, errors::*};
+use crate::{state::*, constants::*, errors::*, utils::*};
 
 #[derive(Accounts)]
 pub struct ApproveAndRelease<'info> {
+    // The creator, or one of the approvers on a multisig bounty
     #[account(
-        constraint = creator.key() == bounty.creator @ BountyError::OnlyCreatorCanPerform
+        constraint = bounty.can_approve(&creator.key()) @ BountyError::OnlyCreatorCanPerform
     )]
     pub creator: Signer<'info>,
     
@@ -16,14 +17,16 @@ pub struct ApproveAndRelease<'info> {
             &[bounty.nonce]
         ],
         bump = bounty.bump,
-        constraint = bounty.status == BountyStatus::Submitted @ BountyError::BountyNotInReview,
+        // Approved is accepted so a late approval after auto_release is a no-op
+        constraint = bounty.status == BountyStatus::Submitted
+            || bounty.status == BountyStatus::Approved @ BountyError::BountyNotInReview,
     )]
     pub bounty: Account<'info, Bounty>,
     
     /// CHECK: This is the auditor who will receive the funds
     #[account(
         mut,
-        constraint = Some(auditor.key()) == bounty.auditor @ BountyError::InvalidEscrowAccount,
+        constraint = Some(auditor.key()) == bounty.payout_recipient() @ BountyError::InvalidAuditorAccount,
     )]
     pub auditor: AccountInfo<'info>,
     
@@ -33,12 +36,41 @@ pub struct ApproveAndRelease<'info> {
             ESCROW_SEED,
             bounty.key().as_ref()
         ],
-        bump,
-        seeds::program = system_program.key()
+        bump = bounty.escrow_bump,
+        constraint = escrow.owner == &anchor_lang::system_program::ID @ BountyError::InvalidEscrowAccount,
     )]
     /// CHECK: This is the escrow PDA that holds the funds
     pub escrow: AccountInfo<'info>,
     
+    #[account(
+        mut,
+        seeds = [METRICS_SEED],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, GlobalMetrics>,
+    
+    // Required for multisig bounties
+    #[account(
+        mut,
+        seeds = [
+            APPROVAL_SEED,
+            bounty.key().as_ref()
+        ],
+        bump = approval_state.bump,
+    )]
+    pub approval_state: Option<Account<'info, ApprovalState>>,
+    
+    // Required once the bounty was created with an event log
+    #[account(
+        mut,
+        seeds = [
+            EVENT_LOG_SEED,
+            bounty.key().as_ref()
+        ],
+        bump = event_log.bump,
+    )]
+    pub event_log: Option<Account<'info, EventLog>>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -47,34 +79,61 @@ pub fn handler(ctx: Context<ApproveAndRelease>) -> Result<()> {
     let auditor = &ctx.accounts.auditor;
     let escrow = &ctx.accounts.escrow;
     
-    // Transfer funds from escrow to auditor
-    let amount = bounty.amount;
-    
-    let bounty_key = bounty.key();
-    let escrow_bump = ctx.bumps.escrow;
-    let escrow_seeds = &[
-        ESCROW_SEED,
-        bounty_key.as_ref(),
-        &[escrow_bump],
-    ];
-    
-    // Use invoke_signed to transfer funds from escrow PDA to auditor
-    anchor_lang::solana_program::program::invoke_signed(
-        &anchor_lang::solana_program::system_instruction::transfer(
-            escrow.key,
-            auditor.key,
-            amount,
-        ),
-        &[
-            escrow.clone(),
-            auditor.clone(),
-            ctx.accounts.system_program.to_account_info(),
-        ],
-        &[escrow_seeds],
-    )?;
+    // Auto-release may have already paid the auditor; never pay twice
+    if bounty.status == BountyStatus::Approved {
+        msg!("Bounty already approved, nothing to release");
+        return Ok(());
+    }
+    
+    // Multisig creators release only once enough distinct approvers signed
+    if bounty.creator_is_multisig {
+        let approval_state = ctx.accounts.approval_state.as_mut()
+            .ok_or(BountyError::ApprovalStateRequired)?;
+        let approver = ctx.accounts.creator.key();
+        if !approval_state.approvals.contains(&approver) {
+            approval_state.approvals.push(approver);
+        }
+        let approvals = approval_state.approvals.len();
+        if approvals < bounty.approval_threshold as usize {
+            msg!("Approval recorded ({}/{})", approvals, bounty.approval_threshold);
+            record_event(bounty, &mut ctx.accounts.event_log, EventKind::ApprovalRecorded, approver, 0)?;
+            return Ok(());
+        }
+    }
+    
+    // Past the auto-release deadline the creator can still approve, as long
+    // as nobody has called auto_release yet. Both paths pay the same amount.
+    let now = now()?;
+    if bounty.auto_release_ready(now) {
+        msg!("Approving after the auto-release deadline");
+    }
     
     // Update bounty status
-    bounty.status = BountyStatus::Approved;
+    bounty.approved_at = now;
+    bounty.set_status(BountyStatus::Approved)?;
+    
+    // With a dispute window the transfer is deferred to execute_release
+    if bounty.dispute_window > 0 {
+        bounty.release_pending = true;
+        record_event(bounty, &mut ctx.accounts.event_log, EventKind::Approved, ctx.accounts.creator.key(), 0)?;
+        return Ok(());
+    }
+    
+    // Transfer funds from escrow to auditor, keeping any holdback in escrow
+    let amount = bounty.release_payout(escrow.lamports());
+    
+    // Transfer funds from escrow PDA to auditor
+    transfer_from_escrow(
+        escrow,
+        auditor,
+        &ctx.accounts.system_program,
+        &bounty.key(),
+        bounty.escrow_bump,
+        amount,
+    )?;
+    
+    ctx.accounts.metrics.record_paid(amount, bounty.release_amount());
+    record_event(bounty, &mut ctx.accounts.event_log, EventKind::Approved, ctx.accounts.creator.key(), amount)?;
     
     Ok(())
 } 
\ No newline at end of file
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, constants::*, errors::*};
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    // The creator standing by its approval, or the recovery authority
+    #[account(
+        constraint = resolver.key() == bounty.creator
+            || Some(resolver.key()) == bounty.recovery_authority @ BountyError::OnlyCreatorCanPerform
+    )]
+    pub resolver: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [
+            BOUNTY_SEED, 
+            bounty.creator.as_ref(), 
+            &[bounty.nonce]
+        ],
+        bump = bounty.bump,
+        constraint = bounty.status == BountyStatus::Approved @ BountyError::BountyNotCompleted,
+        constraint = bounty.release_pending @ BountyError::NoPendingRelease,
+        constraint = bounty.disputed @ BountyError::NoActiveDispute,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    
+    // Required once the bounty was created with an event log
+    #[account(
+        mut,
+        seeds = [
+            EVENT_LOG_SEED,
+            bounty.key().as_ref()
+        ],
+        bump = event_log.bump,
+    )]
+    pub event_log: Option<Account<'info, EventLog>>,
+}
+
+pub fn handler(ctx: Context<ResolveDispute>) -> Result<()> {
+    let bounty = &mut ctx.accounts.bounty;
+    
+    // Dismiss the dispute; execute_release can pay once the window is over.
+    // Upholding it is recover_bounty, which refunds the recovery destination.
+    bounty.disputed = false;
+    bounty.disputed_by = None;
+    
+    record_event(bounty, &mut ctx.accounts.event_log, EventKind::DisputeDismissed, ctx.accounts.resolver.key(), 0)?;
+    
+    Ok(())
+}
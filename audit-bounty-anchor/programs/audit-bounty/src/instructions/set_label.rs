@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, constants::*, errors::*};
+
+#[derive(Accounts)]
+pub struct SetLabel<'info> {
+    #[account(
+        constraint = creator.key() == bounty.creator @ BountyError::OnlyCreatorCanPerform
+    )]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [
+            BOUNTY_SEED, 
+            bounty.creator.as_ref(), 
+            &[bounty.nonce]
+        ],
+        bump = bounty.bump,
+        constraint = bounty.status == BountyStatus::Open @ BountyError::BountyNotOpen,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    
+    // Required once the bounty was created with an event log
+    #[account(
+        mut,
+        seeds = [
+            EVENT_LOG_SEED,
+            bounty.key().as_ref()
+        ],
+        bump = event_log.bump,
+    )]
+    pub event_log: Option<Account<'info, EventLog>>,
+}
+
+pub fn handler(ctx: Context<SetLabel>, label: Option<String>) -> Result<()> {
+    let bounty = &mut ctx.accounts.bounty;
+    
+    if !Bounty::validate_label(&label) {
+        return Err(BountyError::InvalidLabel.into());
+    }
+    
+    bounty.label = label;
+    
+    record_event(bounty, &mut ctx.accounts.event_log, EventKind::LabelSet, ctx.accounts.creator.key(), 0)?;
+    
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, constants::*, errors::*};
+
+#[derive(Accounts)]
+pub struct SetMaxTvl<'info> {
+    #[account(
+        constraint = authority.key() == metrics.authority @ BountyError::OnlyMetricsAuthorityCanPerform
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [METRICS_SEED],
+        bump = metrics.bump,
+    )]
+    pub metrics: Account<'info, GlobalMetrics>,
+}
+
+pub fn handler(ctx: Context<SetMaxTvl>, max_tvl: u64) -> Result<()> {
+    // Lowering the cap below what is locked only blocks new bounties
+    ctx.accounts.metrics.max_tvl = max_tvl;
+    
+    Ok(())
+}
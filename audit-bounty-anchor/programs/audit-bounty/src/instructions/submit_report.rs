@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
-use crate::{state::*, constants::*, errors::*};
+use crate::{state::*, constants::*, errors::*, utils::*};
 
 #[derive(Accounts)]
+#[instruction(report_uri: String)]
 pub struct SubmitReport<'info> {
     #[account(mut)]
     pub auditor: Signer<'info>,
@@ -16,23 +17,77 @@ pub struct SubmitReport<'info> {
         bump = bounty.bump,
         constraint = bounty.status == BountyStatus::Open @ BountyError::BountyNotOpen,
         constraint = bounty.auditor.is_none() @ BountyError::AuditorAlreadyAssigned,
+        constraint = auditor.key() != bounty.creator @ BountyError::CannotAuditOwnBounty,
+        constraint = report_uri.len() <= Bounty::MAX_REPORT_URI_SIZE @ BountyError::ReportLinkTooLong,
+        constraint = bounty.report_scheme_allowed(&report_uri) @ BountyError::ReportSchemeNotAllowed,
     )]
     pub bounty: Account<'info, Bounty>,
+    
+    // Required when the bounty has terms to accept
+    #[account(
+        seeds = [
+            ACCEPTANCE_SEED,
+            bounty.key().as_ref(),
+            auditor.key().as_ref()
+        ],
+        bump = acceptance.bump,
+    )]
+    pub acceptance: Option<Account<'info, Acceptance>>,
+    
+    // Required once the bounty was created with an event log
+    #[account(
+        mut,
+        seeds = [
+            EVENT_LOG_SEED,
+            bounty.key().as_ref()
+        ],
+        bump = event_log.bump,
+    )]
+    pub event_log: Option<Account<'info, EventLog>>,
 }
 
-pub fn handler(ctx: Context<SubmitReport>, report_uri: String) -> Result<()> {
+pub fn handler(
+    ctx: Context<SubmitReport>,
+    report_uri: String,
+    template_hash: [u8; 32],
+    report_hash: [u8; 32],
+) -> Result<()> {
     let bounty = &mut ctx.accounts.bounty;
     let auditor = &ctx.accounts.auditor;
     
-    // Validate report URI length
-    if report_uri.len() > Bounty::MAX_REPORT_URI_SIZE {
-        return Err(BountyError::ReportLinkTooLong.into());
+    if !bounty.submissions_open(now()?) {
+        return Err(BountyError::SubmissionNotYetOpen.into());
+    }
+    
+    // Auditor must have accepted the current terms first
+    if let Some(required) = bounty.required_terms_hash {
+        let accepted = ctx.accounts.acceptance.as_ref()
+            .is_some_and(|acceptance| acceptance.terms_hash == required);
+        if !accepted {
+            return Err(BountyError::TermsNotAccepted.into());
+        }
+    }
+    
+    // The hash is computed off-chain over the report structure
+    if let Some(required) = bounty.required_template_hash {
+        if required != template_hash {
+            return Err(BountyError::TemplateMismatch.into());
+        }
+    }
+    
+    // Commits to the report content, since the URI may be repointed later
+    if report_hash == [0u8; 32] {
+        return Err(BountyError::InvalidReportHash.into());
     }
     
     // Update bounty status
     bounty.auditor = Some(*auditor.key);
     bounty.report_uri = Some(report_uri);
-    bounty.status = BountyStatus::Submitted;
+    bounty.report_template_hash = Some(template_hash);
+    bounty.report_hash = Some(report_hash);
+    bounty.set_status(BountyStatus::Submitted)?;
+    
+    record_event(bounty, &mut ctx.accounts.event_log, EventKind::ReportSubmitted, auditor.key(), 0)?;
     
     Ok(())
 } 
\ No newline at end of file
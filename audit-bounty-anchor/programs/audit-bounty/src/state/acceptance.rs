@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+// Auditor's on-chain consent to a bounty's terms
+#[account]
+pub struct Acceptance {
+    pub bounty: Pubkey,                // Bounty the terms belong to
+    pub auditor: Pubkey,               // Wallet that accepted the terms
+    pub terms_hash: [u8; 32],          // Hash of the accepted terms
+    pub accepted_at: i64,              // Unix timestamp
+    pub bump: u8,                      // PDA bump
+}
+
+impl Acceptance {
+    pub fn space() -> usize {
+        8 +                              // Discriminator
+        32 +                             // bounty: Pubkey
+        32 +                             // auditor: Pubkey
+        32 +                             // terms_hash: [u8; 32]
+        8 +                              // accepted_at: i64
+        1                                // bump: u8
+    }
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+use crate::state::Bounty;
+
+// Approvals collected from a multisig creator's approvers for the current report
+#[account]
+pub struct ApprovalState {
+    pub bounty: Pubkey,                // Bounty being approved
+    pub approvals: Vec<Pubkey>,        // Distinct approvers who signed off
+    pub bump: u8,                      // PDA bump
+}
+
+impl ApprovalState {
+    pub fn space() -> usize {
+        8 +                              // Discriminator
+        32 +                             // bounty: Pubkey
+        4 + 32 * Bounty::MAX_APPROVERS + // Vec<Pubkey> for approvals
+        1                                // bump: u8
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::{constants::*, errors::*};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
 pub enum BountyStatus {
@@ -8,30 +9,578 @@ pub enum BountyStatus {
     Cancelled,
 }
 
+// Category ids stored in Bounty::tags
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum BountyCategory {
+    SmartContract = 0,                 // On-chain program code
+    Defi = 1,                          // Lending, AMMs, derivatives
+    Nft = 2,                           // NFT and marketplace programs
+    Governance = 3,                    // DAOs and voting
+    Bridge = 4,                        // Cross-chain bridges
+    Infrastructure = 5,                // Validators, RPC, tooling
+    Frontend = 6,                      // Web and wallet front ends
+    Other = 7,
+}
+
+impl BountyCategory {
+    pub const COUNT: u8 = 8;
+}
+
+// version, creator, status and tags lead the layout so they sit at fixed
+// offsets for getProgramAccounts memcmp filters; keep them first when
+// adding fields, and bump VERSION with a migration when the layout changes.
 #[account]
 pub struct Bounty {
+    pub version: u8,                   // Layout version, Bounty::VERSION once migrated
     pub creator: Pubkey,               // Wallet of the creator
+    pub status: BountyStatus,          // Open, Submitted, Approved, Cancelled
+    pub tags: Vec<u8>,                 // BountyCategory ids, at most MAX_TAGS
     pub auditor: Option<Pubkey>,       // Wallet of the assigned auditor
     pub amount: u64,                   // Amount locked in the bounty
-    pub status: BountyStatus,          // Open, Submitted, Approved, Cancelled
     pub report_uri: Option<String>,    // IPFS or Arweave link to the report
     pub created_at: i64,               // Unix timestamp
+    pub created_slot: u64,             // Slot at creation, tiebreaker for created_at
     pub nonce: u8,                     // For PDA derivation
     pub bump: u8,                      // PDA bump
+    pub holdback_bps: u16,             // Share of the payout held back after approval
+    pub holdback_release_at: i64,      // When the auditor may claim the holdback
+    pub holdback_claimed: bool,        // Whether the holdback has been paid out
+    pub recovery_authority: Option<Pubkey>,   // May cancel if the creator key is compromised
+    pub recovery_destination: Option<Pubkey>, // Only place recovered funds can go
+    pub required_template_hash: Option<[u8; 32]>, // Report template the creator requires
+    pub report_template_hash: Option<[u8; 32]>,   // Template hash of the submitted report
+    pub dispute_window: i64,           // Seconds an approval can be disputed before release
+    pub approved_at: i64,              // Unix timestamp of approval
+    pub release_pending: bool,         // Approved but funds not yet released
+    pub disputed: bool,                // Pending release frozen by a dispute
+    pub required_terms_hash: Option<[u8; 32]>, // Terms auditors must accept before submitting
+    pub payout_override: Option<Pubkey>, // Creator-set recipient replacing the auditor's wallet
+    pub forfeit_destination: Option<Pubkey>, // Receives cancelled funds instead of the creator
+    pub creator_is_multisig: bool,     // Approval needs approval_threshold of approvers
+    pub approvers: Vec<Pubkey>,        // Signers allowed to approve a multisig bounty
+    pub approval_threshold: u8,        // Distinct approvals needed before release
+    pub allowed_schemes: Vec<String>,  // Accepted report_uri prefixes, empty allows any
+    pub event_log_enabled: bool,       // State changes are recorded in the EventLog PDA
+    pub creator_veto_until: i64,       // A rejection before this time blocks auto-release, 0 if unset
+    pub auto_release_vetoed: bool,     // Creator rejected within the veto window, so later reports need an approval
+    pub report_hash: Option<[u8; 32]>, // Content hash of the submitted report, fixed until rejected
+    pub label: Option<String>,         // Display name, e.g. "Season 3 Grant Pool"
+    pub reviewed_at: Option<i64>,      // When the creator confirmed seeing the current report
+    pub escrow_bump: u8,               // Canonical escrow bump, saves re-deriving it
+    pub disputed_at: i64,              // Unix timestamp of the open dispute
+    pub disputed_by: Option<Pubkey>,   // Signer of the open dispute
+    pub submission_start: i64,         // Reports are accepted from this time on, 0 if unset
 }
 
 impl Bounty {
     pub const MAX_REPORT_URI_SIZE: usize = 100; // Define max size for report_uri
+    pub const MAX_BPS: u16 = 10_000;
+    
+    pub const VERSION: u8 = 1;
+    
+    // Byte offsets into the account data, including the 8-byte discriminator
+    pub const VERSION_OFFSET: usize = 8;
+    pub const CREATOR_OFFSET: usize = Self::VERSION_OFFSET + 1;
+    pub const STATUS_OFFSET: usize = Self::CREATOR_OFFSET + 32;
+    pub const TAGS_LEN_OFFSET: usize = Self::STATUS_OFFSET + 1; // u32 tag count
+    pub const TAGS_OFFSET: usize = Self::TAGS_LEN_OFFSET + 4;   // First tag id
+    
+    pub const MAX_TAGS: usize = 5;
+    pub const MAX_APPROVERS: usize = 5;
+    pub const MAX_SCHEMES: usize = 4;
+    pub const MAX_SCHEME_SIZE: usize = 16;
+    pub const MAX_LABEL_SIZE: usize = 32;
     
     pub fn space() -> usize {
         8 +                              // Discriminator
+        1 +                              // version: u8
         32 +                             // creator: Pubkey
+        1 +                              // status (enum)
+        4 + Self::MAX_TAGS +             // Vec<u8> for tags
         1 + 32 +                         // Option<Pubkey> for auditor
         8 +                              // amount: u64
-        1 +                              // status (enum)
-        1 + Self::MAX_REPORT_URI_SIZE +  // Option<String> for report_uri
+        1 + 4 + Self::MAX_REPORT_URI_SIZE + // Option<String> for report_uri (tag, length prefix, bytes)
         8 +                              // created_at: i64
+        8 +                              // created_slot: u64
         1 +                              // nonce: u8
-        1                                // bump: u8
+        1 +                              // bump: u8
+        2 +                              // holdback_bps: u16
+        8 +                              // holdback_release_at: i64
+        1 +                              // holdback_claimed: bool
+        1 + 32 +                         // Option<Pubkey> for recovery_authority
+        1 + 32 +                         // Option<Pubkey> for recovery_destination
+        1 + 32 +                         // Option<[u8; 32]> for required_template_hash
+        1 + 32 +                         // Option<[u8; 32]> for report_template_hash
+        8 +                              // dispute_window: i64
+        8 +                              // approved_at: i64
+        1 +                              // release_pending: bool
+        1 +                              // disputed: bool
+        1 + 32 +                         // Option<[u8; 32]> for required_terms_hash
+        1 + 32 +                         // Option<Pubkey> for payout_override
+        1 + 32 +                         // Option<Pubkey> for forfeit_destination
+        1 +                              // creator_is_multisig: bool
+        4 + 32 * Self::MAX_APPROVERS +   // Vec<Pubkey> for approvers
+        1 +                              // approval_threshold: u8
+        4 + Self::MAX_SCHEMES * (4 + Self::MAX_SCHEME_SIZE) + // Vec<String> for allowed_schemes
+        1 +                              // event_log_enabled: bool
+        8 +                              // creator_veto_until: i64
+        1 +                              // auto_release_vetoed: bool
+        1 + 32 +                         // Option<[u8; 32]> for report_hash
+        1 + 4 + Self::MAX_LABEL_SIZE +   // Option<String> for label
+        1 + 8 +                          // Option<i64> for reviewed_at
+        1 +                              // escrow_bump: u8
+        8 +                              // disputed_at: i64
+        1 + 32 +                         // Option<Pubkey> for disputed_by
+        8                                // submission_start: i64
+    }
+
+    // Escrow PDA for a bounty. It is derived under this program (not the
+    // system program) so the program can sign transfers out of it.
+    pub fn find_escrow_address(bounty: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[ESCROW_SEED, bounty.as_ref()], &crate::ID)
+    }
+
+    pub fn validate_tags(tags: &[u8]) -> bool {
+        tags.len() <= Self::MAX_TAGS && tags.iter().all(|tag| *tag < BountyCategory::COUNT)
+    }
+
+    pub fn validate_approvers(approvers: &[Pubkey], approval_threshold: u8) -> bool {
+        if approvers.is_empty() {
+            return approval_threshold == 0;
+        }
+        let distinct = approvers
+            .iter()
+            .enumerate()
+            .all(|(i, approver)| !approvers[..i].contains(approver));
+        approvers.len() <= Self::MAX_APPROVERS
+            && distinct
+            && approval_threshold >= 1
+            && approval_threshold as usize <= approvers.len()
+    }
+
+    pub fn validate_schemes(schemes: &[String]) -> bool {
+        schemes.len() <= Self::MAX_SCHEMES
+            && schemes.iter().all(|scheme| !scheme.is_empty() && scheme.len() <= Self::MAX_SCHEME_SIZE)
+    }
+
+    // map_or rather than is_none_or, which the SBF toolchain's rustc lacks
+    #[allow(clippy::unnecessary_map_or)]
+    pub fn validate_label(label: &Option<String>) -> bool {
+        label.as_ref().map_or(true, |label| label.len() <= Self::MAX_LABEL_SIZE)
+    }
+
+    pub fn report_scheme_allowed(&self, report_uri: &str) -> bool {
+        self.allowed_schemes.is_empty()
+            || self.allowed_schemes.iter().any(|scheme| report_uri.starts_with(scheme.as_str()))
+    }
+
+    // Creator, or one of the approvers when the creator is a multisig
+    pub fn can_approve(&self, signer: &Pubkey) -> bool {
+        if self.creator_is_multisig {
+            self.approvers.contains(signer)
+        } else {
+            *signer == self.creator
+        }
+    }
+
+    // Whether enough distinct approvers are among the signers. Multisig
+    // bounties need this for anything the creator key alone could abuse.
+    pub fn has_quorum(&self, signers: &[Pubkey]) -> bool {
+        let approved = self
+            .approvers
+            .iter()
+            .filter(|approver| signers.contains(approver))
+            .count();
+        approved >= self.approval_threshold as usize
+    }
+
+    // Wallet that receives the auditor's payout
+    pub fn payout_recipient(&self) -> Option<Pubkey> {
+        self.payout_override.or(self.auditor)
+    }
+
+    // Anyone may release funds to the auditor from this time on
+    pub fn auto_release_at(&self) -> i64 {
+        self.created_at + AUTO_RELEASE_DEADLINE
+    }
+
+    // A rejection at this time still blocks auto-release for later reports
+    pub fn in_veto_window(&self, now: i64) -> bool {
+        now < self.creator_veto_until
+    }
+
+    // Unset (0) or past start times accept reports immediately
+    pub fn submissions_open(&self, now: i64) -> bool {
+        now >= self.submission_start
+    }
+
+    pub fn auto_release_ready(&self, now: i64) -> bool {
+        now >= self.auto_release_at()
+    }
+
+    // Disputes can be opened until this time; the release executes after it
+    pub fn dispute_window_ends_at(&self) -> i64 {
+        self.approved_at + self.dispute_window
+    }
+
+    // A dispute nobody resolved stops holding up the release at this time
+    pub fn dispute_expires_at(&self) -> i64 {
+        self.disputed_at + DISPUTE_RESOLUTION_PERIOD
+    }
+
+    pub fn release_frozen(&self, now: i64) -> bool {
+        self.disputed && now < self.dispute_expires_at()
+    }
+
+    // Open bounties, and approvals whose release is frozen by a third
+    // party's dispute. A dispute from the creator's side is theirs to
+    // dismiss, so it cannot be used to claw back an approved payout.
+    pub fn recoverable(&self, now: i64) -> bool {
+        if self.status == BountyStatus::Open {
+            return true;
+        }
+        let creator_side = self.disputed_by == Some(self.creator)
+            || (self.disputed_by.is_some() && self.disputed_by == self.recovery_authority);
+        self.status == BountyStatus::Approved
+            && self.release_pending
+            && self.release_frozen(now)
+            && !creator_side
+    }
+
+    // Nothing can leave the escrow any more, so the accounts that only
+    // exist to get funds out of it can be closed
+    pub fn settled(&self) -> bool {
+        match self.status {
+            BountyStatus::Cancelled => true,
+            BountyStatus::Approved => {
+                !self.release_pending && (self.holdback_bps == 0 || self.holdback_claimed)
+            }
+            BountyStatus::Open | BountyStatus::Submitted => false,
+        }
+    }
+
+    pub fn holdback_released(&self, now: i64) -> bool {
+        now >= self.holdback_release_at
+    }
+
+    // Portion of the amount kept in escrow until holdback_release_at
+    pub fn holdback_amount(&self) -> u64 {
+        Self::holdback_for(self.amount, self.holdback_bps)
+    }
+
+    pub fn holdback_for(amount: u64, holdback_bps: u16) -> u64 {
+        ((amount as u128) * (holdback_bps as u128) / (Self::MAX_BPS as u128)) as u64
+    }
+
+    // Portion of the amount paid to the auditor on approval
+    pub fn release_amount(&self) -> u64 {
+        self.amount - self.holdback_amount()
+    }
+
+    // What approval actually transfers: the whole escrow but the holdback.
+    // Lamports sent to the escrow directly go to the auditor too; paying only
+    // release_amount() would strand them below rent exemption and the
+    // runtime would reject the transfer.
+    pub fn release_payout(&self, escrow_balance: u64) -> u64 {
+        escrow_balance.saturating_sub(self.holdback_amount())
+    }
+
+    // All status writes after creation go through here
+    pub fn set_status(&mut self, status: BountyStatus) -> Result<()> {
+        if !bounty_can_transition(self.status, status) {
+            return Err(BountyError::InvalidStatusTransition.into());
+        }
+        self.status = status;
+        Ok(())
+    }
+}
+
+// Bounty as first deployed, before it carried a version. Only read by
+// migrate_bounty, which rewrites such accounts in the current layout.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyBounty {
+    pub creator: Pubkey,
+    pub auditor: Option<Pubkey>,
+    pub amount: u64,
+    pub status: BountyStatus,
+    pub report_uri: Option<String>,
+    pub created_at: i64,
+    pub nonce: u8,
+    pub bump: u8,
+}
+
+impl LegacyBounty {
+    // Account size the first release allocated, discriminator included
+    pub const SPACE: usize = 8 + 32 + 1 + 32 + 8 + 1 + 1 + Bounty::MAX_REPORT_URI_SIZE + 8 + 1 + 1;
+}
+
+impl Bounty {
+    // Current-layout bounty with the legacy fields and every later feature
+    // off, as if created without any of the newer options
+    pub fn from_legacy(legacy: LegacyBounty, escrow_bump: u8) -> Self {
+        Self {
+            version: Self::VERSION,
+            creator: legacy.creator,
+            status: legacy.status,
+            tags: Vec::new(),
+            auditor: legacy.auditor,
+            amount: legacy.amount,
+            report_uri: legacy.report_uri,
+            created_at: legacy.created_at,
+            created_slot: 0,
+            nonce: legacy.nonce,
+            bump: legacy.bump,
+            holdback_bps: 0,
+            holdback_release_at: 0,
+            holdback_claimed: false,
+            recovery_authority: None,
+            recovery_destination: None,
+            required_template_hash: None,
+            report_template_hash: None,
+            dispute_window: 0,
+            approved_at: 0,
+            release_pending: false,
+            disputed: false,
+            required_terms_hash: None,
+            payout_override: None,
+            forfeit_destination: None,
+            creator_is_multisig: false,
+            approvers: Vec::new(),
+            approval_threshold: 0,
+            allowed_schemes: Vec::new(),
+            event_log_enabled: false,
+            creator_veto_until: 0,
+            auto_release_vetoed: false,
+            report_hash: None,
+            label: None,
+            reviewed_at: None,
+            escrow_bump,
+            disputed_at: 0,
+            disputed_by: None,
+            submission_start: 0,
+        }
+    }
+}
+
+// Legal status transitions. Cancelled is terminal, and Approved is left
+// only when the recovery authority upholds a dispute.
+pub fn bounty_can_transition(from: BountyStatus, to: BountyStatus) -> bool {
+    matches!(
+        (from, to),
+        (BountyStatus::Open, BountyStatus::Submitted)           // submit_report
+            | (BountyStatus::Open, BountyStatus::Cancelled)     // cancel_bounty
+            | (BountyStatus::Submitted, BountyStatus::Open)     // reject_report
+            | (BountyStatus::Submitted, BountyStatus::Approved) // approve_and_release, auto_release
+            | (BountyStatus::Approved, BountyStatus::Cancelled) // recover_bounty on a disputed release
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    // Every optional field set and every collection at its limit
+    fn full_bounty() -> Bounty {
+        let key = Pubkey::new_unique();
+        Bounty {
+            version: Bounty::VERSION,
+            creator: key,
+            status: BountyStatus::Submitted,
+            tags: vec![BountyCategory::Other as u8; Bounty::MAX_TAGS],
+            auditor: Some(key),
+            amount: u64::MAX,
+            report_uri: Some("x".repeat(Bounty::MAX_REPORT_URI_SIZE)),
+            created_at: i64::MAX,
+            created_slot: u64::MAX,
+            nonce: u8::MAX,
+            bump: u8::MAX,
+            holdback_bps: Bounty::MAX_BPS,
+            holdback_release_at: i64::MAX,
+            holdback_claimed: true,
+            recovery_authority: Some(key),
+            recovery_destination: Some(key),
+            required_template_hash: Some([u8::MAX; 32]),
+            report_template_hash: Some([u8::MAX; 32]),
+            dispute_window: i64::MAX,
+            approved_at: i64::MAX,
+            release_pending: true,
+            disputed: true,
+            required_terms_hash: Some([u8::MAX; 32]),
+            payout_override: Some(key),
+            forfeit_destination: Some(key),
+            creator_is_multisig: true,
+            approvers: vec![key; Bounty::MAX_APPROVERS],
+            approval_threshold: u8::MAX,
+            allowed_schemes: vec!["x".repeat(Bounty::MAX_SCHEME_SIZE); Bounty::MAX_SCHEMES],
+            event_log_enabled: true,
+            creator_veto_until: i64::MAX,
+            auto_release_vetoed: true,
+            report_hash: Some([u8::MAX; 32]),
+            label: Some("x".repeat(Bounty::MAX_LABEL_SIZE)),
+            reviewed_at: Some(i64::MAX),
+            escrow_bump: u8::MAX,
+            disputed_at: i64::MAX,
+            disputed_by: Some(key),
+            submission_start: i64::MAX,
+        }
+    }
+
+    #[test]
+    fn holdback_splits_the_amount() {
+        for (bps, holdback) in [(0, 0), (1, 1), (9_999, 9_999), (10_000, 10_000)] {
+            assert_eq!(Bounty::holdback_for(10_000, bps), holdback);
+        }
+        // Rounds down, so the auditor never gets less than their share
+        assert_eq!(Bounty::holdback_for(9_999, 1), 0);
+        assert_eq!(Bounty::holdback_for(1, 9_999), 0);
+
+        let mut bounty = full_bounty();
+        bounty.amount = 10_000;
+        for (bps, release) in [(0, 10_000), (1, 9_999), (9_999, 1), (10_000, 0)] {
+            bounty.holdback_bps = bps;
+            assert_eq!(bounty.release_amount(), release);
+            assert_eq!(bounty.release_amount() + bounty.holdback_amount(), bounty.amount);
+        }
+    }
+
+    #[test]
+    fn holdback_does_not_overflow_near_u64_max() {
+        assert_eq!(Bounty::holdback_for(u64::MAX, 10_000), u64::MAX);
+        assert_eq!(Bounty::holdback_for(u64::MAX, 5_000), u64::MAX / 2);
+        assert_eq!(Bounty::holdback_for(u64::MAX, 1), u64::MAX / 10_000);
+        assert_eq!(Bounty::holdback_for(u64::MAX, 0), 0);
+
+        let mut bounty = full_bounty();
+        for bps in [0, 1, 9_999, 10_000] {
+            bounty.holdback_bps = bps;
+            assert_eq!(bounty.release_amount() + bounty.holdback_amount(), u64::MAX);
+        }
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn tags_must_be_known_categories() {
+        assert!(Bounty::validate_tags(&[]));
+        assert!(Bounty::validate_tags(&[BountyCategory::SmartContract as u8, BountyCategory::Other as u8]));
+        assert!(!Bounty::validate_tags(&[BountyCategory::COUNT]));
+        assert!(!Bounty::validate_tags(&[u8::MAX]));
+
+        assert!(Bounty::validate_tags(&[0; Bounty::MAX_TAGS]));
+        assert!(!Bounty::validate_tags(&[0; Bounty::MAX_TAGS + 1]));
+    }
+
+    #[test]
+    fn report_scheme_must_match_an_allowed_prefix() {
+        let mut bounty = full_bounty();
+        bounty.allowed_schemes = Vec::new();
+        assert!(bounty.report_scheme_allowed("https://gateway.example/report"));
+
+        bounty.allowed_schemes = vec!["ipfs://".to_string(), "ar://".to_string()];
+        assert!(bounty.report_scheme_allowed("ipfs://bafy"));
+        assert!(bounty.report_scheme_allowed("ar://tx"));
+        assert!(!bounty.report_scheme_allowed("https://ipfs://bafy"));
+        assert!(!bounty.report_scheme_allowed("ipfs:/bafy"));
+        assert!(!bounty.report_scheme_allowed(""));
+    }
+
+    #[test]
+    fn approvers_must_be_distinct_and_cover_the_threshold() {
+        let [a, b, c] = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+
+        assert!(Bounty::validate_approvers(&[], 0));
+        assert!(!Bounty::validate_approvers(&[], 1));
+        assert!(Bounty::validate_approvers(&[a, b, c], 2));
+        assert!(Bounty::validate_approvers(&[a, b, c], 3));
+        assert!(!Bounty::validate_approvers(&[a, b, c], 0));
+        assert!(!Bounty::validate_approvers(&[a, b, c], 4));
+        assert!(!Bounty::validate_approvers(&[a, b, a], 2));
+
+        let too_many: Vec<Pubkey> = (0..=Bounty::MAX_APPROVERS).map(|_| Pubkey::new_unique()).collect();
+        assert!(!Bounty::validate_approvers(&too_many, 1));
+    }
+
+    #[test]
+    fn time_gates_open_exactly_at_their_deadline() {
+        let mut bounty = full_bounty();
+        bounty.created_at = 1_700_000_000;
+        bounty.holdback_release_at = 1_800_000_000;
+        bounty.creator_veto_until = 1_750_000_000;
+        bounty.submission_start = 1_710_000_000;
+
+        let deadline = bounty.auto_release_at();
+        assert!(!bounty.auto_release_ready(deadline - 1));
+        assert!(bounty.auto_release_ready(deadline));
+
+        assert!(!bounty.holdback_released(bounty.holdback_release_at - 1));
+        assert!(bounty.holdback_released(bounty.holdback_release_at));
+
+        assert!(bounty.in_veto_window(bounty.creator_veto_until - 1));
+        assert!(!bounty.in_veto_window(bounty.creator_veto_until));
+
+        assert!(!bounty.submissions_open(bounty.submission_start - 1));
+        assert!(bounty.submissions_open(bounty.submission_start));
+    }
+
+    #[test]
+    fn space_fits_a_fully_populated_bounty() {
+        let mut data = Vec::new();
+        full_bounty().try_serialize(&mut data).unwrap();
+        // space() counts the discriminator that try_serialize also writes
+        assert_eq!(Bounty::space(), data.len());
+    }
+
+    #[test]
+    fn filter_offsets_match_the_serialized_layout() {
+        let mut bounty = full_bounty();
+        bounty.tags = vec![BountyCategory::Defi as u8, BountyCategory::Bridge as u8];
+        let mut data = Vec::new();
+        bounty.try_serialize(&mut data).unwrap();
+
+        assert_eq!(&data[..8], &Bounty::DISCRIMINATOR);
+        assert_eq!(data[Bounty::VERSION_OFFSET], Bounty::VERSION);
+        assert_eq!(&data[Bounty::CREATOR_OFFSET..Bounty::STATUS_OFFSET], bounty.creator.as_ref());
+        assert_eq!(data[Bounty::STATUS_OFFSET], BountyStatus::Submitted as u8);
+        assert_eq!(&data[Bounty::TAGS_LEN_OFFSET..Bounty::TAGS_OFFSET], &2u32.to_le_bytes());
+        assert_eq!(&data[Bounty::TAGS_OFFSET..Bounty::TAGS_OFFSET + 2], &bounty.tags[..]);
+    }
+
+    #[test]
+    fn transition_matrix() {
+        use BountyStatus::*;
+        let all = [Open, Submitted, Approved, Cancelled];
+        let allowed = [
+            (Open, Submitted),
+            (Open, Cancelled),
+            (Submitted, Open),
+            (Submitted, Approved),
+            (Approved, Cancelled),
+        ];
+        for from in all {
+            for to in all {
+                assert_eq!(
+                    bounty_can_transition(from, to),
+                    allowed.contains(&(from, to)),
+                    "{:?} -> {:?}",
+                    from as u8,
+                    to as u8,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn escrow_is_derived_under_this_program() {
+        let bounty = Pubkey::new_unique();
+        let (escrow, bump) = Bounty::find_escrow_address(&bounty);
+        assert_eq!(
+            Pubkey::create_program_address(&[ESCROW_SEED, bounty.as_ref(), &[bump]], &crate::ID),
+            Ok(escrow)
+        );
+        let (system_derived, _) = Pubkey::find_program_address(
+            &[ESCROW_SEED, bounty.as_ref()],
+            &anchor_lang::system_program::ID,
+        );
+        assert_ne!(escrow, system_derived);
+    }
+}
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use crate::{state::Bounty, errors::*};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum EventKind {
+    Created,
+    ReportSubmitted,
+    Approved,
+    Released,
+    Rejected,
+    Cancelled,
+    AutoReleased,
+    HoldbackClaimed,
+    Recovered,
+    Disputed,
+    PayoutReassigned,
+    DisputeDismissed,
+    LabelSet,
+    ApprovalRecorded,
+    Reviewed,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct EventRecord {
+    pub kind: EventKind,               // What happened
+    pub actor: Pubkey,                 // Signer that caused it
+    pub amount: u64,                   // Lamports moved, 0 if none
+    pub slot: u64,                     // Slot it happened in
+}
+
+impl EventRecord {
+    pub const SIZE: usize = 1 + 32 + 8 + 8;
+}
+
+// Append-only history of a bounty's state changes, kept on-chain so it
+// outlives RPC transaction history. Holds the last CAPACITY records; once
+// full, each new record overwrites the oldest one.
+#[account]
+pub struct EventLog {
+    pub bounty: Pubkey,                // Bounty being logged
+    pub total: u64,                    // Records ever written, next slot is total % CAPACITY
+    pub records: Vec<EventRecord>,     // Ring buffer, grows to CAPACITY then wraps
+    pub bump: u8,                      // PDA bump
+}
+
+impl EventLog {
+    pub const CAPACITY: usize = 32;
+
+    pub fn space() -> usize {
+        8 +                              // Discriminator
+        32 +                             // bounty: Pubkey
+        8 +                              // total: u64
+        4 + Self::CAPACITY * EventRecord::SIZE + // Vec<EventRecord> for records
+        1                                // bump: u8
+    }
+
+    pub fn push(&mut self, record: EventRecord) {
+        if self.records.len() < Self::CAPACITY {
+            self.records.push(record);
+        } else {
+            self.records[(self.total % Self::CAPACITY as u64) as usize] = record;
+        }
+        self.total += 1;
+    }
+}
+
+// Record an event if the bounty keeps a log. Bounties created with a log
+// must pass it to every state-changing instruction so no event is missed.
+pub fn record_event(
+    bounty: &Bounty,
+    event_log: &mut Option<Account<EventLog>>,
+    kind: EventKind,
+    actor: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    if !bounty.event_log_enabled {
+        return Ok(());
+    }
+    let event_log = event_log.as_mut().ok_or(BountyError::EventLogRequired)?;
+    event_log.push(EventRecord {
+        kind,
+        actor,
+        amount,
+        slot: Clock::get()?.slot,
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(amount: u64) -> EventRecord {
+        EventRecord {
+            kind: EventKind::Created,
+            actor: Pubkey::default(),
+            amount,
+            slot: amount,
+        }
+    }
+
+    #[test]
+    fn push_wraps_around_once_full() {
+        let mut log = EventLog {
+            bounty: Pubkey::default(),
+            total: 0,
+            records: Vec::new(),
+            bump: 0,
+        };
+        let capacity = EventLog::CAPACITY as u64;
+        for amount in 0..capacity + 3 {
+            log.push(record(amount));
+        }
+
+        assert_eq!(log.total, capacity + 3);
+        assert_eq!(log.records.len(), EventLog::CAPACITY);
+        // The three oldest records were overwritten in place
+        assert_eq!(log.records[0].amount, capacity);
+        assert_eq!(log.records[2].amount, capacity + 2);
+        assert_eq!(log.records[3].amount, 3);
+        assert_eq!(log.records[EventLog::CAPACITY - 1].amount, capacity - 1);
+    }
+}
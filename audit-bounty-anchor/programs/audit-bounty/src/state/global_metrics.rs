@@ -0,0 +1,174 @@
+use anchor_lang::prelude::*;
+use crate::errors::*;
+
+// Program-wide lamport totals for dashboards, one account per program
+#[account]
+pub struct GlobalMetrics {
+    pub total_created: u64,            // Escrowed by create_bounty
+    pub total_paid: u64,               // Released to auditors, holdbacks and top-ups included
+    pub total_refunded: u64,           // Returned by cancel_bounty and recover_bounty, top-ups included
+    pub bump: u8,                      // PDA bump
+    pub total_locked: u64,             // Committed bounty amounts not yet paid out or refunded
+    pub max_tvl: u64,                  // Cap on total_locked
+    pub authority: Pubkey,             // May change max_tvl, the upgrade authority at initialization
+    pub total_topped_up: u64,          // Paid out or refunded beyond the committed amounts
+}
+
+// Returned by get_metrics. Lamports leaving an escrow were either committed
+// at creation or sent to it directly, so
+// total_created + total_topped_up == total_paid + total_refunded + total_locked.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MetricsSnapshot {
+    pub total_created: u64,
+    pub total_paid: u64,
+    pub total_refunded: u64,
+    pub total_topped_up: u64,
+    pub total_locked: u64,
+    pub max_tvl: u64,
+}
+
+impl GlobalMetrics {
+    pub fn space() -> usize {
+        8 +                              // Discriminator
+        8 +                              // total_created: u64
+        8 +                              // total_paid: u64
+        8 +                              // total_refunded: u64
+        1 +                              // bump: u8
+        8 +                              // total_locked: u64
+        8 +                              // max_tvl: u64
+        32 +                             // authority: Pubkey
+        8                                // total_topped_up: u64
+    }
+
+    // Lamports committed to escrows. Tracked by bounty amount rather than
+    // by the lamports moved, which can include direct transfers to an
+    // escrow and would otherwise free up room under the cap.
+    pub fn locked(&self) -> u64 {
+        self.total_locked
+    }
+
+    pub fn lock(&mut self, amount: u64) -> Result<()> {
+        match self.total_locked.checked_add(amount) {
+            Some(locked) if locked <= self.max_tvl => {
+                self.total_locked = locked;
+                Ok(())
+            }
+            _ => Err(BountyError::TvlCapExceeded.into()),
+        }
+    }
+
+    pub fn unlock(&mut self, amount: u64) {
+        self.total_locked = self.total_locked.saturating_sub(amount);
+    }
+
+    // The totals only feed dashboards, so they saturate rather than fail a
+    // payout or refund
+    pub fn record_created(&mut self, amount: u64) {
+        self.total_created = self.total_created.saturating_add(amount);
+    }
+
+    // `moved` left the escrow, of which `committed` was the bounty's own
+    // amount and the rest had been transferred to the escrow directly
+    pub fn record_paid(&mut self, moved: u64, committed: u64) {
+        self.total_paid = self.total_paid.saturating_add(moved);
+        self.release(moved, committed);
+    }
+
+    pub fn record_refunded(&mut self, moved: u64, committed: u64) {
+        self.total_refunded = self.total_refunded.saturating_add(moved);
+        self.release(moved, committed);
+    }
+
+    fn release(&mut self, moved: u64, committed: u64) {
+        self.unlock(committed);
+        self.total_topped_up = self.total_topped_up.saturating_add(moved.saturating_sub(committed));
+    }
+
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        MetricsSnapshot {
+            total_created: self.total_created,
+            total_paid: self.total_paid,
+            total_refunded: self.total_refunded,
+            total_topped_up: self.total_topped_up,
+            total_locked: self.total_locked,
+            max_tvl: self.max_tvl,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metrics(max_tvl: u64) -> GlobalMetrics {
+        GlobalMetrics {
+            total_created: 0,
+            total_paid: 0,
+            total_refunded: 0,
+            bump: 0,
+            total_locked: 0,
+            max_tvl,
+            authority: Pubkey::default(),
+            total_topped_up: 0,
+        }
+    }
+
+    #[test]
+    fn lock_is_bounded_by_max_tvl() {
+        let mut metrics = metrics(100);
+        metrics.lock(60).unwrap();
+        metrics.lock(40).unwrap();
+        assert_eq!(metrics.locked(), 100);
+        assert!(metrics.lock(1).is_err());
+        assert_eq!(metrics.locked(), 100);
+    }
+
+    #[test]
+    fn lock_does_not_overflow() {
+        let mut metrics = metrics(u64::MAX);
+        metrics.lock(u64::MAX).unwrap();
+        assert!(metrics.lock(1).is_err());
+    }
+
+    #[test]
+    fn unlock_saturates_at_zero() {
+        let mut metrics = metrics(100);
+        metrics.lock(10).unwrap();
+        metrics.unlock(25);
+        assert_eq!(metrics.locked(), 0);
+    }
+
+    #[test]
+    fn totals_saturate_instead_of_wrapping() {
+        let mut metrics = metrics(u64::MAX);
+        metrics.record_created(u64::MAX);
+        metrics.record_created(1);
+        assert_eq!(metrics.total_created, u64::MAX);
+
+        metrics.record_paid(u64::MAX, 0);
+        metrics.record_paid(1, 0);
+        assert_eq!(metrics.total_paid, u64::MAX);
+        assert_eq!(metrics.total_topped_up, u64::MAX);
+
+        metrics.record_refunded(u64::MAX, 1);
+        metrics.record_refunded(1, 1);
+        assert_eq!(metrics.total_refunded, u64::MAX);
+    }
+
+    #[test]
+    fn top_ups_are_what_moved_beyond_the_committed_amount() {
+        let mut metrics = metrics(u64::MAX);
+        metrics.lock(10).unwrap();
+        metrics.record_created(10);
+        metrics.record_paid(7, 6);
+        metrics.record_refunded(4, 4);
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.total_topped_up, 1);
+        assert_eq!(snapshot.total_locked, 0);
+        assert_eq!(
+            snapshot.total_created + snapshot.total_topped_up,
+            snapshot.total_paid + snapshot.total_refunded + snapshot.total_locked
+        );
+    }
+}
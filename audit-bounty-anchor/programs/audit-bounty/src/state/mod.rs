@@ -1,3 +1,11 @@
 mod bounty;
+mod acceptance;
+mod approval_state;
+mod event_log;
+mod global_metrics;
 
-pub use bounty::*; 
\ No newline at end of file
+pub use bounty::*;
+pub use acceptance::*;
+pub use approval_state::*;
+pub use event_log::*;
+pub use global_metrics::*;
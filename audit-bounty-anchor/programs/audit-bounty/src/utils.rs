@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+
+// Single point where handlers read the current time. Time-gated checks take
+// the result as a plain `now` argument (see Bounty::auto_release_ready) so
+// they can be exercised without a Clock sysvar.
+pub fn now() -> Result<i64> {
+    Ok(Clock::get()?.unix_timestamp)
+}
+
+// Keys of the extra accounts that signed, e.g. approvers co-signing an
+// action on a multisig bounty
+pub fn remaining_signers(remaining_accounts: &[AccountInfo]) -> Vec<Pubkey> {
+    remaining_accounts
+        .iter()
+        .filter(|account| account.is_signer)
+        .map(|account| account.key())
+        .collect()
+}
+
+// Moves lamports out of a bounty's escrow PDA. The escrow is derived under
+// this program and held by the system program, so the transfer is a system
+// CPI signed with the escrow seeds.
+pub fn transfer_from_escrow<'info>(
+    escrow: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    bounty: &Pubkey,
+    escrow_bump: u8,
+    amount: u64,
+) -> Result<()> {
+    // Anchor checks the Program type; this catches the struct being loosened later
+    debug_assert_eq!(system_program.key(), anchor_lang::system_program::ID);
+    
+    anchor_lang::solana_program::program::invoke_signed(
+        &anchor_lang::solana_program::system_instruction::transfer(
+            escrow.key,
+            to.key,
+            amount,
+        ),
+        &[
+            escrow.clone(),
+            to.clone(),
+            system_program.to_account_info(),
+        ],
+        &[&[ESCROW_SEED, bounty.as_ref(), &[escrow_bump]]],
+    )?;
+    Ok(())
+}
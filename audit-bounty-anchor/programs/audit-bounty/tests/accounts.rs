@@ -0,0 +1,250 @@
+mod common;
+
+use ::audit_bounty::{accounts, *};
+use anchor_lang::error::ErrorCode;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use common::*;
+
+fn seeds_error() -> ProgramError {
+    Error::from(ErrorCode::ConstraintSeeds).into()
+}
+
+// A bounty under review, plus a second bounty whose escrow is the wrong one
+fn submitted(env: &mut Env, args: instruction::CreateBounty) -> (Pubkey, Pubkey) {
+    let bounty = env.create(args).unwrap();
+    env.submit(&bounty, "ipfs://report").unwrap();
+    let other = env.create(create_args(SOL, 99)).unwrap();
+    (bounty, other)
+}
+
+fn approve_with(env: &mut Env, bounty: Pubkey, auditor: Pubkey, escrow: Pubkey) -> TxResult {
+    let creator = env.creator;
+    env.ledger.process(
+        accounts::ApproveAndRelease {
+            creator,
+            bounty,
+            auditor,
+            escrow,
+            metrics: metrics_address(),
+            approval_state: None,
+            event_log: None,
+            system_program: system_program::ID,
+        },
+        instruction::ApproveAndRelease {},
+    )
+}
+
+fn auto_release_with(env: &mut Env, bounty: Pubkey, auditor: Pubkey, escrow: Pubkey) -> TxResult {
+    let payer = env.ledger.wallet();
+    env.ledger.process(
+        accounts::AutoRelease {
+            payer,
+            bounty,
+            auditor,
+            escrow,
+            metrics: metrics_address(),
+            event_log: None,
+            system_program: system_program::ID,
+        },
+        instruction::AutoRelease {},
+    )
+}
+
+fn execute_release_with(env: &mut Env, bounty: Pubkey, auditor: Pubkey, escrow: Pubkey) -> TxResult {
+    let payer = env.ledger.wallet();
+    env.ledger.process(
+        accounts::ExecuteRelease {
+            payer,
+            bounty,
+            auditor,
+            escrow,
+            metrics: metrics_address(),
+            event_log: None,
+            system_program: system_program::ID,
+        },
+        instruction::ExecuteRelease {},
+    )
+}
+
+fn claim_holdback_with(env: &mut Env, bounty: Pubkey, auditor: Pubkey, escrow: Pubkey) -> TxResult {
+    env.ledger.process(
+        accounts::ClaimHoldback {
+            auditor,
+            bounty,
+            escrow,
+            metrics: metrics_address(),
+            event_log: None,
+            system_program: system_program::ID,
+        },
+        instruction::ClaimHoldback {},
+    )
+}
+
+fn cancel_with(env: &mut Env, bounty: Pubkey, forfeit_destination: Option<Pubkey>, escrow: Pubkey) -> TxResult {
+    let creator = env.creator;
+    env.ledger.process(
+        accounts::CancelBounty {
+            creator,
+            bounty,
+            forfeit_destination,
+            escrow,
+            metrics: metrics_address(),
+            event_log: None,
+            system_program: system_program::ID,
+        },
+        instruction::CancelBounty {},
+    )
+}
+
+fn recover_with(
+    env: &mut Env,
+    bounty: Pubkey,
+    recovery_authority: Pubkey,
+    recovery_destination: Pubkey,
+    escrow: Pubkey,
+) -> TxResult {
+    env.ledger.process(
+        accounts::RecoverBounty {
+            recovery_authority,
+            bounty,
+            recovery_destination,
+            escrow,
+            metrics: metrics_address(),
+            event_log: None,
+            system_program: system_program::ID,
+        },
+        instruction::RecoverBounty {},
+    )
+}
+
+#[test]
+fn approve_rejects_wrong_accounts() {
+    let mut env = Env::new();
+    let (bounty, other) = submitted(&mut env, create_args(SOL, 0));
+    let (auditor, stranger) = (env.auditor, env.ledger.wallet());
+
+    assert_eq!(
+        approve_with(&mut env, bounty, stranger, escrow_address(&bounty)),
+        Err(custom(BountyError::InvalidAuditorAccount))
+    );
+    assert_eq!(approve_with(&mut env, bounty, auditor, escrow_address(&other)), Err(seeds_error()));
+    approve_with(&mut env, bounty, auditor, escrow_address(&bounty)).unwrap();
+}
+
+#[test]
+fn auto_release_rejects_wrong_accounts() {
+    let mut env = Env::new();
+    let (bounty, other) = submitted(&mut env, create_args(SOL, 0));
+    let (auditor, stranger) = (env.auditor, env.ledger.wallet());
+    advance(AUTO_RELEASE_DEADLINE);
+
+    assert_eq!(
+        auto_release_with(&mut env, bounty, stranger, escrow_address(&bounty)),
+        Err(custom(BountyError::InvalidAuditorAccount))
+    );
+    assert_eq!(auto_release_with(&mut env, bounty, auditor, escrow_address(&other)), Err(seeds_error()));
+    auto_release_with(&mut env, bounty, auditor, escrow_address(&bounty)).unwrap();
+}
+
+#[test]
+fn execute_release_rejects_wrong_accounts() {
+    let mut env = Env::new();
+    let (bounty, other) = submitted(
+        &mut env,
+        instruction::CreateBounty { dispute_window: 60, ..create_args(SOL, 0) },
+    );
+    env.approve(&bounty).unwrap();
+    advance(60);
+    let (auditor, stranger) = (env.auditor, env.ledger.wallet());
+
+    assert_eq!(
+        execute_release_with(&mut env, bounty, stranger, escrow_address(&bounty)),
+        Err(custom(BountyError::InvalidAuditorAccount))
+    );
+    assert_eq!(execute_release_with(&mut env, bounty, auditor, escrow_address(&other)), Err(seeds_error()));
+    execute_release_with(&mut env, bounty, auditor, escrow_address(&bounty)).unwrap();
+}
+
+#[test]
+fn claim_holdback_rejects_wrong_accounts() {
+    let mut env = Env::new();
+    let release_at = time() + 60;
+    let (bounty, other) = submitted(
+        &mut env,
+        instruction::CreateBounty {
+            holdback_bps: 5_000,
+            holdback_release_at: release_at,
+            ..create_args(SOL, 0)
+        },
+    );
+    env.approve(&bounty).unwrap();
+    set_time(release_at);
+    let (auditor, stranger) = (env.auditor, env.ledger.wallet());
+
+    assert_eq!(
+        claim_holdback_with(&mut env, bounty, stranger, escrow_address(&bounty)),
+        Err(custom(BountyError::InvalidAuditorAccount))
+    );
+    assert_eq!(claim_holdback_with(&mut env, bounty, auditor, escrow_address(&other)), Err(seeds_error()));
+    claim_holdback_with(&mut env, bounty, auditor, escrow_address(&bounty)).unwrap();
+}
+
+#[test]
+fn cancel_rejects_wrong_accounts() {
+    let mut env = Env::new();
+    let destination = env.ledger.wallet();
+    let stranger = env.ledger.wallet();
+    let bounty = env
+        .create(instruction::CreateBounty {
+            forfeit_destination: Some(destination),
+            ..create_args(SOL, 0)
+        })
+        .unwrap();
+    let other = env.create(create_args(SOL, 99)).unwrap();
+
+    assert_eq!(
+        cancel_with(&mut env, bounty, Some(stranger), escrow_address(&bounty)),
+        Err(custom(BountyError::InvalidForfeitDestination))
+    );
+    assert_eq!(
+        cancel_with(&mut env, bounty, None, escrow_address(&bounty)),
+        Err(custom(BountyError::InvalidForfeitDestination))
+    );
+    assert_eq!(cancel_with(&mut env, bounty, Some(destination), escrow_address(&other)), Err(seeds_error()));
+    let creator_before = env.ledger.lamports(&env.creator);
+    let before = env.ledger.lamports(&destination);
+    cancel_with(&mut env, bounty, Some(destination), escrow_address(&bounty)).unwrap();
+    assert_eq!(env.ledger.lamports(&destination), before + SOL);
+    assert_eq!(env.ledger.lamports(&env.creator), creator_before);
+}
+
+#[test]
+fn recover_rejects_wrong_accounts() {
+    let mut env = Env::new();
+    let authority = env.ledger.wallet();
+    let destination = env.ledger.wallet();
+    let stranger = env.ledger.wallet();
+    let bounty = env
+        .create(instruction::CreateBounty {
+            recovery_authority: Some(authority),
+            recovery_destination: Some(destination),
+            ..create_args(SOL, 0)
+        })
+        .unwrap();
+    let other = env.create(create_args(SOL, 99)).unwrap();
+
+    assert_eq!(
+        recover_with(&mut env, bounty, stranger, destination, escrow_address(&bounty)),
+        Err(custom(BountyError::OnlyRecoveryAuthorityCanPerform))
+    );
+    assert_eq!(
+        recover_with(&mut env, bounty, authority, stranger, escrow_address(&bounty)),
+        Err(custom(BountyError::InvalidRecoveryDestination))
+    );
+    assert_eq!(
+        recover_with(&mut env, bounty, authority, destination, escrow_address(&other)),
+        Err(seeds_error())
+    );
+    recover_with(&mut env, bounty, authority, destination, escrow_address(&bounty)).unwrap();
+}
@@ -0,0 +1,48 @@
+mod common;
+
+use ::audit_bounty::*;
+use common::*;
+
+#[test]
+fn approve_right_at_the_auto_release_boundary() {
+    let mut env = Env::new();
+    let bounty = env.create(create_args(SOL, 0)).unwrap();
+    env.submit(&bounty, "ipfs://report").unwrap();
+
+    let deadline = env.bounty(&bounty).auto_release_at();
+    set_time(deadline);
+    let before = env.ledger.lamports(&env.auditor);
+    env.approve(&bounty).unwrap();
+    assert_eq!(env.ledger.lamports(&env.auditor), before + SOL);
+
+    // Auto-release finds nothing left to pay
+    assert_eq!(env.auto_release(&bounty), Err(custom(BountyError::BountyNotInReview)));
+    assert_eq!(env.ledger.lamports(&env.auditor), before + SOL);
+}
+
+#[test]
+fn approve_after_auto_release_does_not_pay_twice() {
+    let mut env = Env::new();
+    let bounty = env.create(create_args(SOL, 0)).unwrap();
+    env.submit(&bounty, "ipfs://report").unwrap();
+
+    set_time(env.bounty(&bounty).auto_release_at());
+    let before = env.ledger.lamports(&env.auditor);
+    env.auto_release(&bounty).unwrap();
+
+    env.approve(&bounty).unwrap();
+    assert_eq!(env.ledger.lamports(&env.auditor), before + SOL);
+    assert_eq!(env.metrics().total_paid, SOL);
+}
+
+#[test]
+fn approve_just_before_the_boundary() {
+    let mut env = Env::new();
+    let bounty = env.create(create_args(SOL, 0)).unwrap();
+    env.submit(&bounty, "ipfs://report").unwrap();
+
+    set_time(env.bounty(&bounty).auto_release_at() - 1);
+    assert_eq!(env.auto_release(&bounty), Err(custom(BountyError::DeadlineNotReached)));
+    env.approve(&bounty).unwrap();
+    assert!(env.bounty(&bounty).status == BountyStatus::Approved);
+}
@@ -0,0 +1,84 @@
+mod common;
+
+use ::audit_bounty::{accounts, *};
+use anchor_lang::solana_program::system_program;
+use common::*;
+
+#[test]
+fn forfeited_funds_go_to_the_destination() {
+    let mut env = Env::new();
+    let destination = env.ledger.wallet();
+    let bounty = env
+        .create(instruction::CreateBounty {
+            forfeit_destination: Some(destination),
+            ..create_args(SOL, 0)
+        })
+        .unwrap();
+
+    let creator_before = env.ledger.lamports(&env.creator);
+    let before = env.ledger.lamports(&destination);
+    env.cancel(&bounty).unwrap();
+
+    assert_eq!(env.ledger.lamports(&destination), before + SOL);
+    assert_eq!(env.ledger.lamports(&env.creator), creator_before);
+    assert!(!env.ledger.exists(&escrow_address(&bounty)));
+}
+
+#[test]
+fn wrong_forfeit_destination_is_rejected() {
+    let mut env = Env::new();
+    let destination = env.ledger.wallet();
+    let stranger = env.ledger.wallet();
+    let bounty = env
+        .create(instruction::CreateBounty {
+            forfeit_destination: Some(destination),
+            ..create_args(SOL, 0)
+        })
+        .unwrap();
+
+    let creator = env.creator;
+
+    let result = env.ledger.process(
+        accounts::CancelBounty {
+            creator,
+            bounty,
+            forfeit_destination: Some(stranger),
+            escrow: escrow_address(&bounty),
+            metrics: metrics_address(),
+            event_log: None,
+            system_program: system_program::ID,
+        },
+        instruction::CancelBounty {},
+    );
+    assert_eq!(result, Err(custom(BountyError::InvalidForfeitDestination)));
+    assert_eq!(env.ledger.lamports(&stranger), 1_000 * SOL);
+    assert_eq!(env.ledger.lamports(&escrow_address(&bounty)), SOL);
+}
+
+#[test]
+fn exactly_funded_cancel_refunds_the_amount() {
+    let mut env = Env::new();
+    let bounty = env.create(create_args(SOL, 0)).unwrap();
+    assert_eq!(env.ledger.lamports(&escrow_address(&bounty)), SOL);
+
+    let before = env.ledger.lamports(&env.creator);
+    env.cancel(&bounty).unwrap();
+
+    assert_eq!(env.ledger.lamports(&env.creator), before + SOL);
+    assert!(!env.ledger.exists(&escrow_address(&bounty)));
+}
+
+#[test]
+fn over_funded_cancel_refunds_the_whole_escrow() {
+    let mut env = Env::new();
+    let bounty = env.create(create_args(SOL, 0)).unwrap();
+    let escrow = escrow_address(&bounty);
+    env.ledger.airdrop(&escrow, SOL / 2);
+
+    let before = env.ledger.lamports(&env.creator);
+    env.cancel(&bounty).unwrap();
+
+    assert_eq!(env.ledger.lamports(&env.creator), before + SOL + SOL / 2);
+    assert!(!env.ledger.exists(&escrow));
+    assert_eq!(env.metrics().total_refunded, SOL + SOL / 2);
+}
@@ -0,0 +1,806 @@
+// Host-side harness for running the program's instructions without a
+// validator. Accounts live in an in-memory Ledger and instructions go
+// through the program's real entrypoint. The syscall stubs serve the Clock
+// and Rent sysvars, keep the last return data and execute the system
+// program CPIs the handlers make, including the runtime's signer and
+// rent-exemption checks.
+#![allow(dead_code)]
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::sync::Once;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    entrypoint::{ProgramResult, SUCCESS},
+    instruction::{AccountMeta, Instruction},
+    bpf_loader_upgradeable,
+    program_error::ProgramError,
+    program_stubs::{self, SyscallStubs},
+    system_program,
+};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use ::audit_bounty::{accounts, instruction, *};
+
+// Every ledger account is backed by this many bytes so create_account and
+// realloc can grow an AccountInfo's data slice in place.
+const MAX_DATA: usize = 10 * 1024;
+
+// Like the runtime's input buffer, the data slice is preceded by its u64
+// length and the key by the u32 length the instruction started with, which
+// AccountInfo::realloc reads and writes.
+const DATA_HEADER: usize = 8;
+const KEY_HEADER: usize = 4;
+
+pub const SOL: u64 = 1_000_000_000;
+pub const START: i64 = 1_700_000_000;
+
+thread_local! {
+    static NOW: Cell<i64> = const { Cell::new(START) };
+    static SLOT: Cell<u64> = const { Cell::new(1) };
+    static RETURN_DATA: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+}
+
+pub fn time() -> i64 {
+    NOW.with(Cell::get)
+}
+
+pub fn set_time(unix_timestamp: i64) {
+    NOW.with(|time| time.set(unix_timestamp));
+    SLOT.with(|slot| slot.set(slot.get() + 1));
+}
+
+pub fn advance(seconds: i64) {
+    set_time(time() + seconds);
+}
+
+pub fn rent_exempt(len: usize) -> u64 {
+    Rent::default().minimum_balance(len)
+}
+
+pub fn custom(error: BountyError) -> ProgramError {
+    ProgramError::Custom(error.into())
+}
+
+struct Stubs;
+
+impl SyscallStubs for Stubs {
+    fn sol_log(&self, _message: &str) {}
+
+    fn sol_log_data(&self, _fields: &[&[u8]]) {}
+
+    fn sol_set_return_data(&self, data: &[u8]) {
+        RETURN_DATA.with(|return_data| *return_data.borrow_mut() = data.to_vec());
+    }
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = Clock {
+            slot: SLOT.with(Cell::get),
+            unix_timestamp: time(),
+            ..Clock::default()
+        };
+        unsafe { *(var_addr as *mut Clock) = clock };
+        SUCCESS
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { *(var_addr as *mut Rent) = Rent::default() };
+        SUCCESS
+    }
+
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        invoke_system(instruction, account_infos, signers_seeds)
+    }
+}
+
+// The handlers only ever CPI into the system program
+fn invoke_system(
+    ix: &Instruction,
+    infos: &[AccountInfo],
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    assert_eq!(ix.program_id, system_program::ID, "unexpected CPI target");
+
+    // PDA signatures are only valid for addresses derived under this program
+    let signers = signers_seeds
+        .iter()
+        .map(|seeds| Pubkey::create_program_address(seeds, &::audit_bounty::ID))
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(|_| ProgramError::InvalidSeeds)?;
+    let info = |index: usize| {
+        let key = &ix.accounts[index].pubkey;
+        infos
+            .iter()
+            .find(|info| info.key == key)
+            .ok_or(ProgramError::NotEnoughAccountKeys)
+    };
+    for (index, meta) in ix.accounts.iter().enumerate() {
+        if meta.is_signer && !info(index)?.is_signer && !signers.contains(&meta.pubkey) {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+    }
+
+    let data = &ix.data;
+    let u64_at = |at: usize| u64::from_le_bytes(data[at..at + 8].try_into().unwrap());
+    let pubkey_at = |at: usize| Pubkey::try_from(&data[at..at + 32]).unwrap();
+    match u32::from_le_bytes(data[..4].try_into().unwrap()) {
+        // CreateAccount { lamports, space, owner }
+        0 => {
+            let to = info(1)?;
+            if to.lamports() > 0 || to.data_len() > 0 {
+                return Err(ProgramError::AccountAlreadyInitialized);
+            }
+            debit_system_account(info(0)?, to, u64_at(4))?;
+            resize(to, u64_at(12) as usize);
+            to.assign(&pubkey_at(20));
+        }
+        // Assign { owner }
+        1 => info(0)?.assign(&pubkey_at(4)),
+        // Transfer { lamports }
+        2 => debit_system_account(info(0)?, info(1)?, u64_at(4))?,
+        // Allocate { space }
+        8 => resize(info(0)?, u64_at(4) as usize),
+        tag => panic!("unsupported system instruction {tag}"),
+    }
+
+    // The runtime refuses to leave an account funded below rent exemption
+    for index in 0..ix.accounts.len() {
+        let account = info(index)?;
+        if account.lamports() > 0 && account.lamports() < rent_exempt(account.data_len()) {
+            return Err(ProgramError::AccountNotRentExempt);
+        }
+    }
+    Ok(())
+}
+
+fn debit_system_account(from: &AccountInfo, to: &AccountInfo, lamports: u64) -> ProgramResult {
+    if *from.owner != system_program::ID || from.data_len() > 0 {
+        return Err(ProgramError::InvalidArgument);
+    }
+    if from.lamports() < lamports {
+        return Err(ProgramError::InsufficientFunds);
+    }
+    **from.try_borrow_mut_lamports()? -= lamports;
+    **to.try_borrow_mut_lamports()? += lamports;
+    Ok(())
+}
+
+fn resize(info: &AccountInfo, len: usize) {
+    assert!(len <= MAX_DATA);
+    let mut data = info.data.borrow_mut();
+    // SAFETY: the slice points into a MAX_DATA-byte ledger buffer
+    *data = unsafe { std::slice::from_raw_parts_mut(data.as_mut_ptr(), len) };
+}
+
+#[derive(Clone)]
+struct LedgerAccount {
+    lamports: u64,
+    data: Box<[u8]>,
+    len: usize,
+    owner: Pubkey,
+    executable: bool,
+    key: Box<[u8]>,
+}
+
+impl LedgerAccount {
+    fn empty() -> Self {
+        Self {
+            lamports: 0,
+            data: vec![0; DATA_HEADER + MAX_DATA].into_boxed_slice(),
+            len: 0,
+            owner: system_program::ID,
+            executable: false,
+            key: vec![0; KEY_HEADER + 32].into_boxed_slice(),
+        }
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.data[DATA_HEADER..DATA_HEADER + self.len]
+    }
+
+    fn data_mut(&mut self) -> &mut [u8] {
+        &mut self.data[DATA_HEADER..DATA_HEADER + self.len]
+    }
+}
+
+pub struct Ledger {
+    accounts: HashMap<Pubkey, LedgerAccount>,
+}
+
+impl Ledger {
+    pub fn new() -> Self {
+        static STUBS: Once = Once::new();
+        STUBS.call_once(|| {
+            program_stubs::set_syscall_stubs(Box::new(Stubs));
+        });
+        set_time(START);
+
+        let mut ledger = Self { accounts: HashMap::new() };
+        for program in [system_program::ID, ::audit_bounty::ID] {
+            let account = ledger.account_mut(&program);
+            account.executable = true;
+            account.lamports = 1;
+        }
+        ledger
+    }
+
+    // Deploys the program behind the upgradeable loader, as on a cluster,
+    // writing the bincode-encoded loader states by hand
+    pub fn deploy(&mut self, upgrade_authority: Option<Pubkey>) {
+        let loader = bpf_loader_upgradeable::ID;
+        let program_data = program_data_address();
+
+        let mut state = 2u32.to_le_bytes().to_vec();
+        state.extend_from_slice(program_data.as_ref());
+        self.write(&::audit_bounty::ID, loader, &state);
+
+        let mut state = 3u32.to_le_bytes().to_vec();
+        state.extend_from_slice(&0u64.to_le_bytes());
+        match upgrade_authority {
+            Some(authority) => {
+                state.push(1);
+                state.extend_from_slice(authority.as_ref());
+            }
+            None => state.push(0),
+        }
+        self.write(&program_data, loader, &state);
+    }
+
+    // Places an account as if an earlier deployment had written it
+    pub fn write(&mut self, key: &Pubkey, owner: Pubkey, data: &[u8]) {
+        let account = self.account_mut(key);
+        account.len = data.len();
+        account.data_mut().copy_from_slice(data);
+        account.owner = owner;
+        account.lamports = account.lamports.max(rent_exempt(data.len()));
+    }
+
+    fn account_mut(&mut self, key: &Pubkey) -> &mut LedgerAccount {
+        self.accounts.entry(*key).or_insert_with(LedgerAccount::empty)
+    }
+
+    pub fn airdrop(&mut self, key: &Pubkey, lamports: u64) {
+        self.account_mut(key).lamports += lamports;
+    }
+
+    pub fn wallet(&mut self) -> Pubkey {
+        let key = Pubkey::new_unique();
+        self.airdrop(&key, 1_000 * SOL);
+        key
+    }
+
+    pub fn lamports(&self, key: &Pubkey) -> u64 {
+        self.accounts.get(key).map_or(0, |account| account.lamports)
+    }
+
+    pub fn exists(&self, key: &Pubkey) -> bool {
+        self.lamports(key) > 0
+    }
+
+    pub fn data(&self, key: &Pubkey) -> &[u8] {
+        self.accounts[key].data()
+    }
+
+    pub fn owner(&self, key: &Pubkey) -> Pubkey {
+        self.accounts.get(key).map_or(system_program::ID, |account| account.owner)
+    }
+
+    pub fn get<T: AccountDeserialize>(&self, key: &Pubkey) -> T {
+        T::try_deserialize(&mut self.data(key)).unwrap()
+    }
+
+    // Writes a program account directly, for states no instruction reaches
+    pub fn set<T: AccountSerialize + AccountDeserialize>(&mut self, key: &Pubkey, edit: impl FnOnce(&mut T)) {
+        let mut value = self.get::<T>(key);
+        edit(&mut value);
+        value.try_serialize(&mut self.account_mut(key).data_mut()).unwrap();
+    }
+
+    // Runs one instruction as its own transaction: on error nothing changes
+    pub fn process(
+        &mut self,
+        accounts: impl ToAccountMetas,
+        args: impl InstructionData,
+    ) -> std::result::Result<(), ProgramError> {
+        self.process_with_signers(accounts, &[], args)
+    }
+
+    // Extra signers are appended as remaining accounts
+    pub fn process_with_signers(
+        &mut self,
+        accounts: impl ToAccountMetas,
+        signers: &[Pubkey],
+        args: impl InstructionData,
+    ) -> std::result::Result<(), ProgramError> {
+        let mut metas = accounts.to_account_metas(None);
+        metas.extend(signers.iter().map(|signer| AccountMeta::new_readonly(*signer, true)));
+        let snapshot = self.accounts.clone();
+        let result = self.execute(&metas, &args.data());
+        match result {
+            Ok(()) => {
+                // Accounts left without lamports are garbage collected
+                for account in self.accounts.values_mut() {
+                    if account.lamports == 0 {
+                        *account = LedgerAccount::empty();
+                    }
+                }
+            }
+            Err(_) => self.accounts = snapshot,
+        }
+        result
+    }
+
+    // Return data of the last instruction that set any
+    pub fn return_data<T: AnchorDeserialize>(&self) -> T {
+        RETURN_DATA.with(|return_data| T::try_from_slice(&return_data.borrow()).unwrap())
+    }
+
+    fn execute(&mut self, metas: &[AccountMeta], data: &[u8]) -> ProgramResult {
+        for meta in metas {
+            self.account_mut(&meta.pubkey);
+        }
+
+        let lens;
+        let result = {
+            let mut by_key = HashMap::new();
+            for (key, account) in self.accounts.iter_mut() {
+                if !metas.iter().any(|meta| meta.pubkey == *key) {
+                    continue;
+                }
+                let LedgerAccount { lamports, data, len, owner, executable, key: key_buffer } = account;
+                key_buffer[..KEY_HEADER].copy_from_slice(&(*len as u32).to_le_bytes());
+                key_buffer[KEY_HEADER..].copy_from_slice(key.as_ref());
+                // SAFETY: Pubkey is a byte array, so any alignment will do
+                let key = unsafe { &*(key_buffer[KEY_HEADER..].as_ptr() as *const Pubkey) };
+                // AccountInfo::assign writes through the owner reference
+                let owner = unsafe { &*(owner as *mut Pubkey) };
+                let data = &mut data[DATA_HEADER..DATA_HEADER + *len];
+                let info = AccountInfo::new(key, false, false, lamports, data, owner, *executable, 0);
+                by_key.insert(*key, info);
+            }
+            let infos: Vec<AccountInfo> = metas
+                .iter()
+                .map(|meta| {
+                    let mut info = by_key[&meta.pubkey].clone();
+                    info.is_signer = meta.is_signer;
+                    info.is_writable = meta.is_writable;
+                    info
+                })
+                .collect();
+            let result = ::audit_bounty::entry(&::audit_bounty::ID, &infos, data);
+            lens = by_key.iter().map(|(key, info)| (*key, info.data_len())).collect::<Vec<_>>();
+            result
+        };
+        for (key, len) in lens {
+            self.account_mut(&key).len = len;
+        }
+        result
+    }
+}
+
+pub fn bounty_address(creator: &Pubkey, nonce: u8) -> Pubkey {
+    Pubkey::find_program_address(&[BOUNTY_SEED, creator.as_ref(), &[nonce]], &::audit_bounty::ID).0
+}
+
+pub fn escrow_address(bounty: &Pubkey) -> Pubkey {
+    Bounty::find_escrow_address(bounty).0
+}
+
+pub fn program_data_address() -> Pubkey {
+    Pubkey::find_program_address(&[::audit_bounty::ID.as_ref()], &bpf_loader_upgradeable::ID).0
+}
+
+pub fn metrics_address() -> Pubkey {
+    Pubkey::find_program_address(&[METRICS_SEED], &::audit_bounty::ID).0
+}
+
+pub fn approval_address(bounty: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[APPROVAL_SEED, bounty.as_ref()], &::audit_bounty::ID).0
+}
+
+pub fn event_log_address(bounty: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[EVENT_LOG_SEED, bounty.as_ref()], &::audit_bounty::ID).0
+}
+
+pub fn acceptance_address(bounty: &Pubkey, auditor: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[ACCEPTANCE_SEED, bounty.as_ref(), auditor.as_ref()], &::audit_bounty::ID).0
+}
+
+pub fn hash(byte: u8) -> [u8; 32] {
+    [byte; 32]
+}
+
+// create_bounty arguments for a plain bounty: no holdback, recovery,
+// dispute window, terms, tags, multisig or scheme restrictions
+pub fn create_args(amount: u64, nonce: u8) -> instruction::CreateBounty {
+    instruction::CreateBounty {
+        amount,
+        nonce,
+        holdback_bps: 0,
+        holdback_release_at: 0,
+        recovery_authority: None,
+        recovery_destination: None,
+        required_template_hash: None,
+        dispute_window: 0,
+        required_terms_hash: None,
+        tags: Vec::new(),
+        forfeit_destination: None,
+        approvers: Vec::new(),
+        approval_threshold: 0,
+        allowed_schemes: Vec::new(),
+        creator_veto_until: 0,
+        label: None,
+        submission_start: 0,
+    }
+}
+
+pub type TxResult = std::result::Result<(), ProgramError>;
+
+// A ledger with the metrics account initialized plus a creator and an
+// auditor wallet. The instruction helpers pass optional accounts (approval
+// state, event log, acceptance) whenever they exist.
+pub struct Env {
+    pub ledger: Ledger,
+    pub creator: Pubkey,
+    pub auditor: Pubkey,
+    pub upgrade_authority: Pubkey,
+}
+
+impl Env {
+    pub fn new() -> Self {
+        Self::with_max_tvl(u64::MAX)
+    }
+
+    pub fn with_max_tvl(max_tvl: u64) -> Self {
+        let mut ledger = Ledger::new();
+        let creator = ledger.wallet();
+        let auditor = ledger.wallet();
+        let upgrade_authority = ledger.wallet();
+        ledger.deploy(Some(upgrade_authority));
+        let mut env = Self { ledger, creator, auditor, upgrade_authority };
+        env.initialize_metrics(upgrade_authority, max_tvl).unwrap();
+        env
+    }
+
+    pub fn initialize_metrics(&mut self, payer: Pubkey, max_tvl: u64) -> TxResult {
+        self.ledger.process(
+            accounts::InitializeMetrics {
+                payer,
+                metrics: metrics_address(),
+                program: ::audit_bounty::ID,
+                program_data: program_data_address(),
+                system_program: system_program::ID,
+            },
+            instruction::InitializeMetrics { max_tvl },
+        )
+    }
+
+    pub fn migrate(&mut self, bounty: &Pubkey, creator: Pubkey) -> TxResult {
+        self.ledger.process(
+            accounts::MigrateBounty {
+                creator,
+                bounty: *bounty,
+                escrow: escrow_address(bounty),
+                metrics: metrics_address(),
+                system_program: system_program::ID,
+            },
+            instruction::MigrateBounty {},
+        )
+    }
+
+    pub fn set_max_tvl(&mut self, authority: Pubkey, max_tvl: u64) -> TxResult {
+        self.ledger.process(
+            accounts::SetMaxTvl { authority, metrics: metrics_address() },
+            instruction::SetMaxTvl { max_tvl },
+        )
+    }
+
+    pub fn close_acceptance(&mut self, bounty: &Pubkey, auditor: Pubkey) -> TxResult {
+        self.ledger.process(
+            accounts::CloseAcceptance {
+                bounty: *bounty,
+                acceptance: acceptance_address(bounty, &auditor),
+                auditor,
+            },
+            instruction::CloseAcceptance {},
+        )
+    }
+
+    pub fn close_bounty_accounts(&mut self, bounty: &Pubkey) -> TxResult {
+        self.ledger.process(
+            accounts::CloseBountyAccounts {
+                bounty: *bounty,
+                creator: self.creator,
+                approval_state: self.existing(approval_address(bounty)),
+                event_log: self.existing(event_log_address(bounty)),
+            },
+            instruction::CloseBountyAccounts {},
+        )
+    }
+
+    pub fn bounty(&self, bounty: &Pubkey) -> Bounty {
+        self.ledger.get(bounty)
+    }
+
+    pub fn metrics(&self) -> GlobalMetrics {
+        self.ledger.get(&metrics_address())
+    }
+
+    pub fn query_metrics(&mut self) -> MetricsSnapshot {
+        self.ledger
+            .process(accounts::GetMetrics { metrics: metrics_address() }, instruction::GetMetrics {})
+            .unwrap();
+        self.ledger.return_data()
+    }
+
+    // Logged records, oldest first
+    pub fn events(&self, bounty: &Pubkey) -> Vec<EventRecord> {
+        let log: EventLog = self.ledger.get(&event_log_address(bounty));
+        let start = if log.records.len() < EventLog::CAPACITY {
+            0
+        } else {
+            (log.total % EventLog::CAPACITY as u64) as usize
+        };
+        let mut records = log.records;
+        records.rotate_left(start);
+        records
+    }
+
+    pub fn last_event(&self, bounty: &Pubkey) -> EventRecord {
+        let log: EventLog = self.ledger.get(&event_log_address(bounty));
+        log.records[((log.total - 1) % EventLog::CAPACITY as u64) as usize]
+    }
+
+    fn existing(&self, key: Pubkey) -> Option<Pubkey> {
+        self.ledger.exists(&key).then_some(key)
+    }
+
+    fn payee(&self, bounty: &Pubkey) -> Pubkey {
+        self.bounty(bounty).payout_recipient().unwrap_or(self.auditor)
+    }
+
+    pub fn create(&mut self, args: instruction::CreateBounty) -> std::result::Result<Pubkey, ProgramError> {
+        self.create_with_log(args, false)
+    }
+
+    pub fn create_with_log(
+        &mut self,
+        args: instruction::CreateBounty,
+        event_log: bool,
+    ) -> std::result::Result<Pubkey, ProgramError> {
+        let bounty = bounty_address(&self.creator, args.nonce);
+        let multisig = !args.approvers.is_empty();
+        self.ledger.process(
+            accounts::CreateBounty {
+                creator: self.creator,
+                bounty,
+                escrow: escrow_address(&bounty),
+                metrics: metrics_address(),
+                approval_state: multisig.then(|| approval_address(&bounty)),
+                event_log: event_log.then(|| event_log_address(&bounty)),
+                system_program: system_program::ID,
+            },
+            args,
+        )?;
+        Ok(bounty)
+    }
+
+    pub fn submit(&mut self, bounty: &Pubkey, report_uri: &str) -> TxResult {
+        self.submit_as(bounty, self.auditor, report_uri, hash(0), hash(1))
+    }
+
+    pub fn submit_as(
+        &mut self,
+        bounty: &Pubkey,
+        auditor: Pubkey,
+        report_uri: &str,
+        template_hash: [u8; 32],
+        report_hash: [u8; 32],
+    ) -> TxResult {
+        self.ledger.process(
+            accounts::SubmitReport {
+                auditor,
+                bounty: *bounty,
+                acceptance: self.existing(acceptance_address(bounty, &auditor)),
+                event_log: self.existing(event_log_address(bounty)),
+            },
+            instruction::SubmitReport {
+                report_uri: report_uri.to_string(),
+                template_hash,
+                report_hash,
+            },
+        )
+    }
+
+    pub fn accept_terms(&mut self, bounty: &Pubkey, terms_hash: [u8; 32]) -> TxResult {
+        self.ledger.process(
+            accounts::AcceptTerms {
+                auditor: self.auditor,
+                bounty: *bounty,
+                acceptance: acceptance_address(bounty, &self.auditor),
+                system_program: system_program::ID,
+            },
+            instruction::AcceptTerms { terms_hash },
+        )
+    }
+
+    pub fn approve(&mut self, bounty: &Pubkey) -> TxResult {
+        self.approve_as(bounty, self.creator)
+    }
+
+    pub fn approve_as(&mut self, bounty: &Pubkey, signer: Pubkey) -> TxResult {
+        self.ledger.process(
+            accounts::ApproveAndRelease {
+                creator: signer,
+                bounty: *bounty,
+                auditor: self.payee(bounty),
+                escrow: escrow_address(bounty),
+                metrics: metrics_address(),
+                approval_state: self.existing(approval_address(bounty)),
+                event_log: self.existing(event_log_address(bounty)),
+                system_program: system_program::ID,
+            },
+            instruction::ApproveAndRelease {},
+        )
+    }
+
+    pub fn reject(&mut self, bounty: &Pubkey) -> TxResult {
+        self.reject_with(bounty, &[])
+    }
+
+    pub fn reject_with(&mut self, bounty: &Pubkey, cosigners: &[Pubkey]) -> TxResult {
+        self.ledger.process_with_signers(
+            accounts::RejectReport {
+                creator: self.creator,
+                bounty: *bounty,
+                approval_state: self.existing(approval_address(bounty)),
+                event_log: self.existing(event_log_address(bounty)),
+            },
+            cosigners,
+            instruction::RejectReport {},
+        )
+    }
+
+    pub fn cancel(&mut self, bounty: &Pubkey) -> TxResult {
+        self.cancel_with(bounty, &[])
+    }
+
+    pub fn cancel_with(&mut self, bounty: &Pubkey, cosigners: &[Pubkey]) -> TxResult {
+        let forfeit_destination = self.bounty(bounty).forfeit_destination;
+        self.ledger.process_with_signers(
+            accounts::CancelBounty {
+                creator: self.creator,
+                bounty: *bounty,
+                forfeit_destination,
+                escrow: escrow_address(bounty),
+                metrics: metrics_address(),
+                event_log: self.existing(event_log_address(bounty)),
+                system_program: system_program::ID,
+            },
+            cosigners,
+            instruction::CancelBounty {},
+        )
+    }
+
+    pub fn auto_release(&mut self, bounty: &Pubkey) -> TxResult {
+        let payer = self.ledger.wallet();
+        self.ledger.process(
+            accounts::AutoRelease {
+                payer,
+                bounty: *bounty,
+                auditor: self.payee(bounty),
+                escrow: escrow_address(bounty),
+                metrics: metrics_address(),
+                event_log: self.existing(event_log_address(bounty)),
+                system_program: system_program::ID,
+            },
+            instruction::AutoRelease {},
+        )
+    }
+
+    pub fn execute_release(&mut self, bounty: &Pubkey) -> TxResult {
+        let payer = self.ledger.wallet();
+        self.ledger.process(
+            accounts::ExecuteRelease {
+                payer,
+                bounty: *bounty,
+                auditor: self.payee(bounty),
+                escrow: escrow_address(bounty),
+                metrics: metrics_address(),
+                event_log: self.existing(event_log_address(bounty)),
+                system_program: system_program::ID,
+            },
+            instruction::ExecuteRelease {},
+        )
+    }
+
+    pub fn claim_holdback(&mut self, bounty: &Pubkey) -> TxResult {
+        self.ledger.process(
+            accounts::ClaimHoldback {
+                auditor: self.payee(bounty),
+                bounty: *bounty,
+                escrow: escrow_address(bounty),
+                metrics: metrics_address(),
+                event_log: self.existing(event_log_address(bounty)),
+                system_program: system_program::ID,
+            },
+            instruction::ClaimHoldback {},
+        )
+    }
+
+    pub fn open_dispute(&mut self, bounty: &Pubkey, disputer: Pubkey) -> TxResult {
+        self.ledger.process(
+            accounts::OpenDispute {
+                disputer,
+                bounty: *bounty,
+                event_log: self.existing(event_log_address(bounty)),
+            },
+            instruction::OpenDispute {},
+        )
+    }
+
+    pub fn resolve_dispute(&mut self, bounty: &Pubkey, resolver: Pubkey) -> TxResult {
+        self.ledger.process(
+            accounts::ResolveDispute {
+                resolver,
+                bounty: *bounty,
+                event_log: self.existing(event_log_address(bounty)),
+            },
+            instruction::ResolveDispute {},
+        )
+    }
+
+    pub fn recover(&mut self, bounty: &Pubkey, recovery_authority: Pubkey) -> TxResult {
+        let recovery_destination = self.bounty(bounty).recovery_destination.unwrap_or_default();
+        self.ledger.process(
+            accounts::RecoverBounty {
+                recovery_authority,
+                bounty: *bounty,
+                recovery_destination,
+                escrow: escrow_address(bounty),
+                metrics: metrics_address(),
+                event_log: self.existing(event_log_address(bounty)),
+                system_program: system_program::ID,
+            },
+            instruction::RecoverBounty {},
+        )
+    }
+
+    pub fn reassign_payout(&mut self, bounty: &Pubkey, new_recipient: Pubkey) -> TxResult {
+        self.ledger.process(
+            accounts::ReassignPayout {
+                creator: self.creator,
+                bounty: *bounty,
+                event_log: self.existing(event_log_address(bounty)),
+            },
+            instruction::ReassignPayout { new_recipient },
+        )
+    }
+
+    pub fn set_label(&mut self, bounty: &Pubkey, label: Option<&str>) -> TxResult {
+        self.ledger.process(
+            accounts::SetLabel {
+                creator: self.creator,
+                bounty: *bounty,
+                event_log: self.existing(event_log_address(bounty)),
+            },
+            instruction::SetLabel { label: label.map(str::to_string) },
+        )
+    }
+
+    pub fn mark_report_reviewed(&mut self, bounty: &Pubkey) -> TxResult {
+        self.ledger.process(
+            accounts::MarkReportReviewed {
+                creator: self.creator,
+                bounty: *bounty,
+                event_log: self.existing(event_log_address(bounty)),
+            },
+            instruction::MarkReportReviewed {},
+        )
+    }
+}
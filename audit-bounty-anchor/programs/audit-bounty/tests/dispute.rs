@@ -0,0 +1,170 @@
+mod common;
+
+use ::audit_bounty::*;
+use anchor_lang::prelude::Pubkey;
+use common::*;
+
+const WINDOW: i64 = 24 * 60 * 60;
+
+fn approved_with_window(env: &mut Env, args: instruction::CreateBounty) -> Pubkey {
+    let bounty = env
+        .create(instruction::CreateBounty { dispute_window: WINDOW, ..args })
+        .unwrap();
+    env.submit(&bounty, "ipfs://report").unwrap();
+    env.approve(&bounty).unwrap();
+    assert!(env.bounty(&bounty).release_pending);
+    bounty
+}
+
+#[test]
+fn clean_release_after_the_window() {
+    let mut env = Env::new();
+    let bounty = approved_with_window(&mut env, create_args(SOL, 0));
+
+    assert_eq!(env.execute_release(&bounty), Err(custom(BountyError::DisputeWindowActive)));
+
+    advance(WINDOW);
+    let before = env.ledger.lamports(&env.auditor);
+    env.execute_release(&bounty).unwrap();
+    assert_eq!(env.ledger.lamports(&env.auditor), before + SOL);
+    assert!(!env.bounty(&bounty).release_pending);
+}
+
+#[test]
+fn disputed_release_is_frozen() {
+    let mut env = Env::new();
+    let bounty = approved_with_window(&mut env, create_args(SOL, 0));
+    let disputer = env.ledger.wallet();
+
+    env.open_dispute(&bounty, disputer).unwrap();
+    assert_eq!(env.open_dispute(&bounty, disputer), Err(custom(BountyError::ReleaseDisputed)));
+
+    advance(WINDOW);
+    assert_eq!(env.execute_release(&bounty), Err(custom(BountyError::ReleaseDisputed)));
+    assert_eq!(env.ledger.lamports(&escrow_address(&bounty)), SOL);
+}
+
+#[test]
+fn creator_dismissal_unfreezes_the_release() {
+    let mut env = Env::new();
+    let bounty = approved_with_window(&mut env, create_args(SOL, 0));
+    let disputer = env.ledger.wallet();
+    env.open_dispute(&bounty, disputer).unwrap();
+
+    // Only the creator or the recovery authority may resolve
+    assert_eq!(
+        env.resolve_dispute(&bounty, disputer),
+        Err(custom(BountyError::OnlyCreatorCanPerform))
+    );
+    env.resolve_dispute(&bounty, env.creator).unwrap();
+    assert_eq!(
+        env.resolve_dispute(&bounty, env.creator),
+        Err(custom(BountyError::NoActiveDispute))
+    );
+
+    advance(WINDOW);
+    env.execute_release(&bounty).unwrap();
+}
+
+#[test]
+fn unresolved_dispute_expires_into_the_release() {
+    let mut env = Env::new();
+    let bounty = approved_with_window(&mut env, create_args(SOL, 0));
+    let disputer = env.ledger.wallet();
+    env.open_dispute(&bounty, disputer).unwrap();
+    let expires_at = env.bounty(&bounty).dispute_expires_at();
+
+    set_time(expires_at - 1);
+    assert_eq!(env.execute_release(&bounty), Err(custom(BountyError::ReleaseDisputed)));
+
+    set_time(expires_at);
+    env.execute_release(&bounty).unwrap();
+    assert!(!env.ledger.exists(&escrow_address(&bounty)));
+}
+
+#[test]
+fn recovery_authority_upholds_a_dispute() {
+    let mut env = Env::new();
+    let authority = env.ledger.wallet();
+    let destination = env.ledger.wallet();
+    let bounty = approved_with_window(
+        &mut env,
+        instruction::CreateBounty {
+            recovery_authority: Some(authority),
+            recovery_destination: Some(destination),
+            ..create_args(SOL, 0)
+        },
+    );
+
+    // Recovery only reaches an approval once it is disputed
+    assert_eq!(env.recover(&bounty, authority), Err(custom(BountyError::BountyNotOpen)));
+
+    let disputer = env.ledger.wallet();
+    env.open_dispute(&bounty, disputer).unwrap();
+    let before = env.ledger.lamports(&destination);
+    env.recover(&bounty, authority).unwrap();
+
+    assert_eq!(env.ledger.lamports(&destination), before + SOL);
+    assert!(env.bounty(&bounty).status == BountyStatus::Cancelled);
+    assert_eq!(env.execute_release(&bounty), Err(custom(BountyError::BountyNotCompleted)));
+}
+
+#[test]
+fn dispute_window_is_capped() {
+    let mut env = Env::new();
+    let result = env.create(instruction::CreateBounty {
+        dispute_window: MAX_DISPUTE_WINDOW + 1,
+        ..create_args(SOL, 0)
+    });
+    assert_eq!(result, Err(custom(BountyError::InvalidDisputeWindow)));
+
+    env.create(instruction::CreateBounty {
+        dispute_window: MAX_DISPUTE_WINDOW,
+        ..create_args(SOL, 0)
+    })
+    .unwrap();
+}
+
+fn approved_with_recovery(env: &mut Env) -> (Pubkey, Pubkey, Pubkey) {
+    let authority = env.ledger.wallet();
+    let destination = env.ledger.wallet();
+    let bounty = approved_with_window(
+        env,
+        instruction::CreateBounty {
+            recovery_authority: Some(authority),
+            recovery_destination: Some(destination),
+            ..create_args(SOL, 0)
+        },
+    );
+    (bounty, authority, destination)
+}
+
+#[test]
+fn expired_dispute_cannot_be_recovered() {
+    let mut env = Env::new();
+    let (bounty, authority, _) = approved_with_recovery(&mut env);
+    let disputer = env.ledger.wallet();
+    env.open_dispute(&bounty, disputer).unwrap();
+
+    set_time(env.bounty(&bounty).dispute_expires_at());
+    assert_eq!(env.recover(&bounty, authority), Err(custom(BountyError::BountyNotOpen)));
+
+    let before = env.ledger.lamports(&env.auditor);
+    env.execute_release(&bounty).unwrap();
+    assert_eq!(env.ledger.lamports(&env.auditor), before + SOL);
+}
+
+#[test]
+fn creator_side_dispute_cannot_claw_back_the_payout() {
+    let mut env = Env::new();
+    let (bounty, authority, _) = approved_with_recovery(&mut env);
+
+    let creator = env.creator;
+    env.open_dispute(&bounty, creator).unwrap();
+    assert_eq!(env.recover(&bounty, authority), Err(custom(BountyError::BountyNotOpen)));
+
+    env.resolve_dispute(&bounty, creator).unwrap();
+    env.open_dispute(&bounty, authority).unwrap();
+    assert_eq!(env.recover(&bounty, authority), Err(custom(BountyError::BountyNotOpen)));
+    assert_eq!(env.ledger.lamports(&escrow_address(&bounty)), SOL);
+}
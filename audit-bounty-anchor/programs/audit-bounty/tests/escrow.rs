@@ -0,0 +1,60 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use ::audit_bounty::{accounts, *};
+use common::*;
+
+#[test]
+fn escrow_is_derived_and_signed_for_by_this_program() {
+    let mut env = Env::new();
+    let bounty = env.create(create_args(SOL, 0)).unwrap();
+    let escrow = escrow_address(&bounty);
+
+    let (expected, _) = Pubkey::find_program_address(&[ESCROW_SEED, bounty.as_ref()], &::audit_bounty::ID);
+    assert_eq!(escrow, expected);
+    assert_eq!(env.ledger.owner(&escrow), system_program::ID);
+    assert_eq!(env.ledger.lamports(&escrow), SOL);
+
+    // Paying out needs the program to sign for the escrow with its seeds
+    env.submit(&bounty, "ipfs://report").unwrap();
+    let before = env.ledger.lamports(&env.auditor);
+    env.approve(&bounty).unwrap();
+    assert_eq!(env.ledger.lamports(&env.auditor), before + SOL);
+    assert!(!env.ledger.exists(&escrow));
+}
+
+#[test]
+fn escrow_derived_under_the_system_program_is_rejected() {
+    let mut env = Env::new();
+    let bounty = bounty_address(&env.creator, 0);
+    let (escrow, _) = Pubkey::find_program_address(&[ESCROW_SEED, bounty.as_ref()], &system_program::ID);
+
+    let result = env.ledger.process(
+        accounts::CreateBounty {
+            creator: env.creator,
+            bounty,
+            escrow,
+            metrics: metrics_address(),
+            approval_state: None,
+            event_log: None,
+            system_program: system_program::ID,
+        },
+        create_args(SOL, 0),
+    );
+    assert_eq!(result, Err(anchor_lang::error::Error::from(anchor_lang::error::ErrorCode::ConstraintSeeds).into()));
+    assert!(!env.ledger.exists(&bounty));
+}
+
+#[test]
+fn stored_escrow_bump_is_the_canonical_bump() {
+    let mut env = Env::new();
+    let bounty = env.create(create_args(SOL, 0)).unwrap();
+
+    let (_, bump) = Bounty::find_escrow_address(&bounty);
+    assert_eq!(env.bounty(&bounty).escrow_bump, bump);
+
+    // Later instructions validate the escrow with the stored bump
+    env.cancel(&bounty).unwrap();
+    assert!(env.bounty(&bounty).status == BountyStatus::Cancelled);
+}
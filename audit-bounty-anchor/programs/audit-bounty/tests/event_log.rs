@@ -0,0 +1,65 @@
+mod common;
+
+use ::audit_bounty::*;
+use common::*;
+
+#[test]
+fn log_records_each_transition_in_order() {
+    let mut env = Env::new();
+    let bounty = env.create_with_log(create_args(SOL, 0), true).unwrap();
+    let (creator, auditor) = (env.creator, env.auditor);
+
+    env.submit(&bounty, "ipfs://report").unwrap();
+    advance(1);
+    env.reject(&bounty).unwrap();
+    env.submit(&bounty, "ipfs://report-v2").unwrap();
+    advance(1);
+    env.approve(&bounty).unwrap();
+
+    let events: Vec<_> = env
+        .events(&bounty)
+        .iter()
+        .map(|event| (event.kind, event.actor, event.amount))
+        .collect();
+    assert!(
+        events
+            == [
+                (EventKind::Created, creator, SOL),
+                (EventKind::ReportSubmitted, auditor, 0),
+                (EventKind::Rejected, creator, 0),
+                (EventKind::ReportSubmitted, auditor, 0),
+                (EventKind::Approved, creator, SOL),
+            ]
+    );
+
+    let slots: Vec<u64> = env.events(&bounty).iter().map(|event| event.slot).collect();
+    assert!(slots.windows(2).all(|pair| pair[0] <= pair[1]));
+    assert!(slots[0] < slots[4]);
+}
+
+#[test]
+fn log_keeps_the_latest_records_once_full() {
+    let mut env = Env::new();
+    let bounty = env.create_with_log(create_args(SOL, 0), true).unwrap();
+
+    // Created, then a submit and a reject per round
+    let rounds = EventLog::CAPACITY / 2 + 1;
+    for _ in 0..rounds {
+        env.submit(&bounty, "ipfs://report").unwrap();
+        env.reject(&bounty).unwrap();
+    }
+    env.submit(&bounty, "ipfs://report").unwrap();
+    env.approve(&bounty).unwrap();
+
+    let log: EventLog = env.ledger.get(&event_log_address(&bounty));
+    let total = 1 + 2 * rounds as u64 + 2;
+    assert_eq!(log.total, total);
+    assert_eq!(log.records.len(), EventLog::CAPACITY);
+
+    // The oldest records, Created included, were overwritten
+    let events = env.events(&bounty);
+    assert!(events.iter().all(|event| event.kind != EventKind::Created));
+    assert!(events[0].kind == EventKind::ReportSubmitted);
+    assert!(events[EventLog::CAPACITY - 2].kind == EventKind::ReportSubmitted);
+    assert!(events[EventLog::CAPACITY - 1].kind == EventKind::Approved);
+}
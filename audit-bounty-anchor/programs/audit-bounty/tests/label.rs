@@ -0,0 +1,33 @@
+mod common;
+
+use ::audit_bounty::*;
+use common::*;
+
+#[test]
+fn label_reads_back_and_is_logged() {
+    let mut env = Env::new();
+    let bounty = env.create_with_log(create_args(SOL, 0), true).unwrap();
+
+    env.set_label(&bounty, Some("Season 3 Grant Pool")).unwrap();
+    assert_eq!(env.bounty(&bounty).label.as_deref(), Some("Season 3 Grant Pool"));
+
+    let event = env.last_event(&bounty);
+    assert!(event.kind == EventKind::LabelSet);
+    assert_eq!(event.actor, env.creator);
+
+    env.set_label(&bounty, None).unwrap();
+    assert_eq!(env.bounty(&bounty).label, None);
+}
+
+#[test]
+fn label_longer_than_the_limit_is_rejected() {
+    let mut env = Env::new();
+    let bounty = env.create(create_args(SOL, 0)).unwrap();
+
+    let longest = "x".repeat(Bounty::MAX_LABEL_SIZE);
+    env.set_label(&bounty, Some(&longest)).unwrap();
+
+    let too_long = "x".repeat(Bounty::MAX_LABEL_SIZE + 1);
+    assert_eq!(env.set_label(&bounty, Some(&too_long)), Err(custom(BountyError::InvalidLabel)));
+    assert_eq!(env.bounty(&bounty).label, Some(longest));
+}
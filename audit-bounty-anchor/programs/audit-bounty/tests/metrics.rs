@@ -0,0 +1,208 @@
+mod common;
+
+use ::audit_bounty::*;
+use anchor_lang::prelude::Pubkey;
+use common::*;
+
+// Deployed, but initialize_metrics not yet called
+fn uninitialized_metrics() -> (Env, Pubkey) {
+    let mut ledger = Ledger::new();
+    let creator = ledger.wallet();
+    let auditor = ledger.wallet();
+    let upgrade_authority = ledger.wallet();
+    ledger.deploy(Some(upgrade_authority));
+    (Env { ledger, creator, auditor, upgrade_authority }, upgrade_authority)
+}
+
+#[test]
+fn only_the_upgrade_authority_initializes_metrics() {
+    let (mut env, upgrade_authority) = uninitialized_metrics();
+    let stranger = env.ledger.wallet();
+
+    assert_eq!(
+        env.initialize_metrics(stranger, 0),
+        Err(custom(BountyError::OnlyMetricsAuthorityCanPerform))
+    );
+    assert!(!env.ledger.exists(&metrics_address()));
+
+    env.initialize_metrics(upgrade_authority, 3 * SOL).unwrap();
+    assert_eq!(env.metrics().max_tvl, 3 * SOL);
+    assert_eq!(env.metrics().authority, upgrade_authority);
+}
+
+#[test]
+fn immutable_program_cannot_initialize_metrics() {
+    let (mut env, upgrade_authority) = uninitialized_metrics();
+    env.ledger.deploy(None);
+
+    assert_eq!(
+        env.initialize_metrics(upgrade_authority, 3 * SOL),
+        Err(custom(BountyError::OnlyMetricsAuthorityCanPerform))
+    );
+}
+
+#[test]
+fn authority_updates_the_cap() {
+    let mut env = Env::with_max_tvl(SOL);
+    let stranger = env.ledger.wallet();
+    assert_eq!(env.create(create_args(2 * SOL, 0)), Err(custom(BountyError::TvlCapExceeded)));
+
+    assert_eq!(
+        env.set_max_tvl(stranger, u64::MAX),
+        Err(custom(BountyError::OnlyMetricsAuthorityCanPerform))
+    );
+
+    let authority = env.upgrade_authority;
+    env.set_max_tvl(authority, 2 * SOL).unwrap();
+    assert_eq!(env.metrics().max_tvl, 2 * SOL);
+    env.create(create_args(2 * SOL, 0)).unwrap();
+
+    // Lowering the cap below what is locked only blocks new bounties
+    env.set_max_tvl(authority, SOL).unwrap();
+    assert_eq!(env.create(create_args(1, 1)), Err(custom(BountyError::TvlCapExceeded)));
+    assert_eq!(env.metrics().locked(), 2 * SOL);
+}
+
+#[test]
+fn bounties_fill_the_cap_exactly() {
+    let mut env = Env::with_max_tvl(3 * SOL);
+    env.create(create_args(2 * SOL, 0)).unwrap();
+    env.create(create_args(SOL, 1)).unwrap();
+    assert_eq!(env.metrics().locked(), 3 * SOL);
+
+    assert_eq!(env.create(create_args(1, 2)), Err(custom(BountyError::TvlCapExceeded)));
+}
+
+#[test]
+fn bounty_past_the_cap_is_rejected() {
+    let mut env = Env::with_max_tvl(3 * SOL);
+    env.create(create_args(2 * SOL, 0)).unwrap();
+
+    assert_eq!(env.create(create_args(SOL + 1, 1)), Err(custom(BountyError::TvlCapExceeded)));
+    assert_eq!(env.metrics().locked(), 2 * SOL);
+}
+
+#[test]
+fn donations_do_not_free_room_under_the_cap() {
+    let mut env = Env::with_max_tvl(3 * SOL);
+    let cancelled = env.create(create_args(SOL, 0)).unwrap();
+    env.create(create_args(2 * SOL, 1)).unwrap();
+
+    // Cancelling refunds the whole escrow, donation included, but only the
+    // committed amount leaves the cap
+    env.ledger.airdrop(&escrow_address(&cancelled), 5 * SOL);
+    env.cancel(&cancelled).unwrap();
+    assert_eq!(env.metrics().total_refunded, 6 * SOL);
+    assert_eq!(env.metrics().total_topped_up, 5 * SOL);
+    assert_eq!(env.metrics().locked(), 2 * SOL);
+
+    env.create(create_args(SOL, 2)).unwrap();
+    assert_eq!(env.create(create_args(1, 3)), Err(custom(BountyError::TvlCapExceeded)));
+}
+
+#[test]
+fn payouts_free_room_under_the_cap() {
+    let mut env = Env::with_max_tvl(2 * SOL);
+    let bounty = env.create(create_args(2 * SOL, 0)).unwrap();
+    env.ledger.airdrop(&escrow_address(&bounty), SOL);
+    env.submit(&bounty, "ipfs://report").unwrap();
+    env.approve(&bounty).unwrap();
+
+    assert_eq!(env.metrics().total_paid, 3 * SOL);
+    assert_eq!(env.metrics().locked(), 0);
+    env.create(create_args(2 * SOL, 1)).unwrap();
+}
+
+#[test]
+fn metrics_follow_create_payout_and_refund() {
+    let mut env = Env::new();
+    let paid = env.create(create_args(2 * SOL, 0)).unwrap();
+    let refunded = env.create(create_args(SOL, 1)).unwrap();
+
+    let metrics = env.metrics();
+    assert_eq!(metrics.total_created, 3 * SOL);
+    assert_eq!(metrics.total_paid, 0);
+    assert_eq!(metrics.total_refunded, 0);
+    assert_eq!(metrics.locked(), 3 * SOL);
+
+    env.submit(&paid, "ipfs://report").unwrap();
+    env.approve(&paid).unwrap();
+    env.cancel(&refunded).unwrap();
+
+    let metrics = env.metrics();
+    assert_eq!(metrics.total_created, 3 * SOL);
+    assert_eq!(metrics.total_paid, 2 * SOL);
+    assert_eq!(metrics.total_refunded, SOL);
+    assert_eq!(metrics.locked(), 0);
+}
+
+#[test]
+fn holdback_is_paid_in_two_parts() {
+    let mut env = Env::new();
+    let release_at = time() + 60;
+    let bounty = env
+        .create(instruction::CreateBounty {
+            holdback_bps: 2_500,
+            holdback_release_at: release_at,
+            ..create_args(4 * SOL, 0)
+        })
+        .unwrap();
+    env.submit(&bounty, "ipfs://report").unwrap();
+
+    env.approve(&bounty).unwrap();
+    assert_eq!(env.metrics().total_paid, 3 * SOL);
+    assert_eq!(env.metrics().locked(), SOL);
+
+    set_time(release_at);
+    env.claim_holdback(&bounty).unwrap();
+    assert_eq!(env.metrics().total_paid, 4 * SOL);
+    assert_eq!(env.metrics().locked(), 0);
+}
+
+#[test]
+fn query_returns_totals_that_reconcile_with_top_ups() {
+    let mut env = Env::with_max_tvl(10 * SOL);
+    let release_at = time() + 60;
+    let paid = env
+        .create(instruction::CreateBounty {
+            holdback_bps: 2_500,
+            holdback_release_at: release_at,
+            ..create_args(4 * SOL, 0)
+        })
+        .unwrap();
+    let refunded = env.create(create_args(SOL, 1)).unwrap();
+    env.create(create_args(2 * SOL, 2)).unwrap();
+
+    env.ledger.airdrop(&escrow_address(&paid), SOL);
+    env.ledger.airdrop(&escrow_address(&refunded), SOL / 2);
+    env.submit(&paid, "ipfs://report").unwrap();
+    env.approve(&paid).unwrap();
+    env.cancel(&refunded).unwrap();
+
+    let snapshot = env.query_metrics();
+    assert_eq!(
+        snapshot,
+        MetricsSnapshot {
+            total_created: 7 * SOL,
+            total_paid: 4 * SOL,
+            total_refunded: SOL + SOL / 2,
+            total_topped_up: SOL + SOL / 2,
+            total_locked: 3 * SOL,
+            max_tvl: 10 * SOL,
+        }
+    );
+
+    // A top-up after the release is paid out with the holdback
+    env.ledger.airdrop(&escrow_address(&paid), SOL);
+    set_time(release_at);
+    env.claim_holdback(&paid).unwrap();
+
+    let snapshot = env.query_metrics();
+    assert_eq!(snapshot.total_paid, 6 * SOL);
+    assert_eq!(snapshot.total_topped_up, 2 * SOL + SOL / 2);
+    assert_eq!(snapshot.total_locked, 2 * SOL);
+    assert_eq!(
+        snapshot.total_created + snapshot.total_topped_up,
+        snapshot.total_paid + snapshot.total_refunded + snapshot.total_locked
+    );
+}
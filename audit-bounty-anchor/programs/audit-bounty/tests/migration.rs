@@ -0,0 +1,93 @@
+mod common;
+
+use ::audit_bounty::*;
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use common::*;
+
+// Writes a bounty the way the first release laid it out
+fn legacy_bounty(env: &mut Env, status: BountyStatus, nonce: u8) -> Pubkey {
+    let (bounty, bump) = Pubkey::find_program_address(
+        &[BOUNTY_SEED, env.creator.as_ref(), &[nonce]],
+        &::audit_bounty::ID,
+    );
+    let legacy = LegacyBounty {
+        creator: env.creator,
+        auditor: None,
+        amount: SOL,
+        status,
+        report_uri: None,
+        created_at: START - 100,
+        nonce,
+        bump,
+    };
+    let mut data = Bounty::DISCRIMINATOR.to_vec();
+    data.extend(legacy.try_to_vec().unwrap());
+    data.resize(LegacyBounty::SPACE, 0);
+    env.ledger.write(&bounty, ::audit_bounty::ID, &data);
+    bounty
+}
+
+#[test]
+fn legacy_bounty_migrates_to_the_current_layout() {
+    let mut env = Env::new();
+    let bounty = legacy_bounty(&mut env, BountyStatus::Open, 0);
+    let creator = env.creator;
+
+    // Unmigrated accounts do not load as a Bounty
+    assert!(env.submit(&bounty, "ipfs://report").is_err());
+
+    env.migrate(&bounty, creator).unwrap();
+
+    assert_eq!(env.ledger.data(&bounty).len(), Bounty::space());
+    assert!(env.ledger.lamports(&bounty) >= rent_exempt(Bounty::space()));
+    let migrated = env.bounty(&bounty);
+    assert_eq!(migrated.version, Bounty::VERSION);
+    assert_eq!(migrated.creator, creator);
+    assert_eq!(migrated.amount, SOL);
+    assert_eq!(migrated.created_at, START - 100);
+    assert!(migrated.status == BountyStatus::Open);
+
+    // The creator re-funds the new escrow, and the bounty then works as usual
+    assert_eq!(env.ledger.lamports(&escrow_address(&bounty)), SOL);
+    assert_eq!(env.metrics().locked(), SOL);
+    env.submit(&bounty, "ipfs://report").unwrap();
+    let before = env.ledger.lamports(&env.auditor);
+    env.approve(&bounty).unwrap();
+    assert_eq!(env.ledger.lamports(&env.auditor), before + SOL);
+}
+
+#[test]
+fn settled_legacy_bounty_migrates_without_funding() {
+    let mut env = Env::new();
+    let bounty = legacy_bounty(&mut env, BountyStatus::Cancelled, 0);
+    let creator = env.creator;
+
+    env.migrate(&bounty, creator).unwrap();
+
+    assert!(env.bounty(&bounty).status == BountyStatus::Cancelled);
+    assert!(!env.ledger.exists(&escrow_address(&bounty)));
+    assert_eq!(env.metrics().locked(), 0);
+}
+
+#[test]
+fn only_the_creator_migrates() {
+    let mut env = Env::new();
+    let bounty = legacy_bounty(&mut env, BountyStatus::Open, 0);
+    let stranger = env.ledger.wallet();
+
+    assert_eq!(env.migrate(&bounty, stranger), Err(custom(BountyError::OnlyCreatorCanPerform)));
+    assert_eq!(env.ledger.data(&bounty).len(), LegacyBounty::SPACE);
+}
+
+#[test]
+fn current_bounty_cannot_be_migrated_again() {
+    let mut env = Env::new();
+    let legacy = legacy_bounty(&mut env, BountyStatus::Open, 0);
+    let current = env.create(create_args(SOL, 1)).unwrap();
+    let creator = env.creator;
+
+    env.migrate(&legacy, creator).unwrap();
+    assert_eq!(env.migrate(&legacy, creator), Err(custom(BountyError::InvalidLegacyBounty)));
+    assert_eq!(env.migrate(&current, creator), Err(custom(BountyError::InvalidLegacyBounty)));
+}
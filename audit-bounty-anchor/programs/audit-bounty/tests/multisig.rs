@@ -0,0 +1,84 @@
+mod common;
+
+use ::audit_bounty::*;
+use anchor_lang::prelude::Pubkey;
+use common::*;
+
+fn two_of_three(env: &mut Env) -> (Pubkey, [Pubkey; 3]) {
+    let approvers = [env.ledger.wallet(), env.ledger.wallet(), env.ledger.wallet()];
+    let bounty = env
+        .create_with_log(
+            instruction::CreateBounty {
+                approvers: approvers.to_vec(),
+                approval_threshold: 2,
+                ..create_args(SOL, 0)
+            },
+            true,
+        )
+        .unwrap();
+    (bounty, approvers)
+}
+
+#[test]
+fn release_waits_for_two_of_three() {
+    let mut env = Env::new();
+    let (bounty, [a, b, _]) = two_of_three(&mut env);
+    env.submit(&bounty, "ipfs://report").unwrap();
+
+    // The creator key is not an approver
+    assert_eq!(env.approve(&bounty), Err(custom(BountyError::OnlyCreatorCanPerform)));
+
+    env.approve_as(&bounty, a).unwrap();
+    let event = env.last_event(&bounty);
+    assert!(event.kind == EventKind::ApprovalRecorded);
+    assert_eq!(event.actor, a);
+
+    // Approving twice does not count twice
+    env.approve_as(&bounty, a).unwrap();
+    assert!(env.bounty(&bounty).status == BountyStatus::Submitted);
+    assert_eq!(env.ledger.lamports(&escrow_address(&bounty)), SOL);
+
+    let before = env.ledger.lamports(&env.auditor);
+    env.approve_as(&bounty, b).unwrap();
+    assert!(env.bounty(&bounty).status == BountyStatus::Approved);
+    assert_eq!(env.ledger.lamports(&env.auditor), before + SOL);
+}
+
+#[test]
+fn reject_needs_quorum() {
+    let mut env = Env::new();
+    let (bounty, [a, _, c]) = two_of_three(&mut env);
+    env.submit(&bounty, "ipfs://report").unwrap();
+
+    assert_eq!(env.reject(&bounty), Err(custom(BountyError::QuorumNotMet)));
+    assert_eq!(env.reject_with(&bounty, &[a]), Err(custom(BountyError::QuorumNotMet)));
+
+    env.reject_with(&bounty, &[a, c]).unwrap();
+    assert!(env.bounty(&bounty).status == BountyStatus::Open);
+}
+
+#[test]
+fn cancel_needs_quorum() {
+    let mut env = Env::new();
+    let (bounty, [a, b, _]) = two_of_three(&mut env);
+    let outsider = env.ledger.wallet();
+
+    assert_eq!(env.cancel(&bounty), Err(custom(BountyError::QuorumNotMet)));
+    assert_eq!(env.cancel_with(&bounty, &[a, outsider]), Err(custom(BountyError::QuorumNotMet)));
+
+    env.cancel_with(&bounty, &[a, b]).unwrap();
+    assert!(env.bounty(&bounty).status == BountyStatus::Cancelled);
+}
+
+#[test]
+fn payout_cannot_be_reassigned() {
+    let mut env = Env::new();
+    let (bounty, _) = two_of_three(&mut env);
+    env.submit(&bounty, "ipfs://report").unwrap();
+    let wallet = env.ledger.wallet();
+
+    assert_eq!(
+        env.reassign_payout(&bounty, wallet),
+        Err(custom(BountyError::MultisigReassignNotAllowed))
+    );
+}
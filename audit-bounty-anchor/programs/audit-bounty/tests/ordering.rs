@@ -0,0 +1,16 @@
+mod common;
+
+use common::*;
+
+#[test]
+fn same_second_bounties_have_distinct_slots() {
+    let mut env = Env::new();
+    let first = env.create(create_args(SOL, 0)).unwrap();
+    // Same unix timestamp, next slot
+    set_time(time());
+    let second = env.create(create_args(SOL, 1)).unwrap();
+
+    let (first, second) = (env.bounty(&first), env.bounty(&second));
+    assert_eq!(first.created_at, second.created_at);
+    assert!(first.created_slot < second.created_slot);
+}
@@ -0,0 +1,79 @@
+mod common;
+
+use ::audit_bounty::*;
+use common::*;
+
+#[test]
+fn approval_drains_escrow_holding_amount_plus_rent() {
+    let mut env = Env::new();
+    let bounty = env.create(create_args(SOL, 0)).unwrap();
+    let escrow = escrow_address(&bounty);
+    env.ledger.airdrop(&escrow, rent_exempt(0));
+    env.submit(&bounty, "ipfs://report").unwrap();
+
+    let before = env.ledger.lamports(&env.auditor);
+    env.approve(&bounty).unwrap();
+
+    assert_eq!(env.ledger.lamports(&env.auditor), before + SOL + rent_exempt(0));
+    assert!(!env.ledger.exists(&escrow));
+}
+
+#[test]
+fn one_lamport_donation_does_not_block_payout() {
+    let mut env = Env::new();
+    let bounty = env.create(create_args(SOL, 0)).unwrap();
+    let escrow = escrow_address(&bounty);
+    env.ledger.airdrop(&escrow, 1);
+    env.submit(&bounty, "ipfs://report").unwrap();
+
+    let before = env.ledger.lamports(&env.auditor);
+    env.approve(&bounty).unwrap();
+
+    assert_eq!(env.ledger.lamports(&env.auditor), before + SOL + 1);
+    assert!(!env.ledger.exists(&escrow));
+}
+
+#[test]
+fn auto_release_pays_donations_too() {
+    let mut env = Env::new();
+    let bounty = env.create(create_args(SOL, 0)).unwrap();
+    let escrow = escrow_address(&bounty);
+    env.ledger.airdrop(&escrow, 1);
+    env.submit(&bounty, "ipfs://report").unwrap();
+
+    advance(AUTO_RELEASE_DEADLINE);
+    let before = env.ledger.lamports(&env.auditor);
+    env.auto_release(&bounty).unwrap();
+
+    assert_eq!(env.ledger.lamports(&env.auditor), before + SOL + 1);
+    assert!(!env.ledger.exists(&escrow));
+}
+
+#[test]
+fn holdback_claim_sweeps_the_escrow() {
+    let mut env = Env::new();
+    let release_at = time() + 100;
+    let bounty = env
+        .create(instruction::CreateBounty {
+            holdback_bps: 2_000,
+            holdback_release_at: release_at,
+            ..create_args(10 * SOL, 0)
+        })
+        .unwrap();
+    let escrow = escrow_address(&bounty);
+    env.ledger.airdrop(&escrow, 1);
+    env.submit(&bounty, "ipfs://report").unwrap();
+
+    let before = env.ledger.lamports(&env.auditor);
+    env.approve(&bounty).unwrap();
+    assert_eq!(env.ledger.lamports(&env.auditor), before + 8 * SOL + 1);
+    assert_eq!(env.ledger.lamports(&escrow), 2 * SOL);
+
+    // Sent after the release, so only the holdback claim can pay it out
+    env.ledger.airdrop(&escrow, 1);
+    set_time(release_at);
+    env.claim_holdback(&bounty).unwrap();
+
+    assert_eq!(env.ledger.lamports(&env.auditor), before + 10 * SOL + 2);
+    assert!(!env.ledger.exists(&escrow));
+}
@@ -0,0 +1,53 @@
+mod common;
+
+use ::audit_bounty::*;
+use anchor_lang::solana_program::system_program;
+use common::*;
+
+#[test]
+fn redirected_payout_goes_to_the_new_recipient() {
+    let mut env = Env::new();
+    let bounty = env.create(create_args(SOL, 0)).unwrap();
+    env.submit(&bounty, "ipfs://report").unwrap();
+    let recipient = env.ledger.wallet();
+
+    env.reassign_payout(&bounty, recipient).unwrap();
+    assert_eq!(env.bounty(&bounty).payout_recipient(), Some(recipient));
+
+    // The lost auditor key can no longer receive the payout
+    let auditor = env.auditor;
+    let creator = env.creator;
+    let result = env.ledger.process(
+        accounts::ApproveAndRelease {
+            creator,
+            bounty,
+            auditor,
+            escrow: escrow_address(&bounty),
+            metrics: metrics_address(),
+            approval_state: None,
+            event_log: None,
+            system_program: system_program::ID,
+        },
+        instruction::ApproveAndRelease {},
+    );
+    assert_eq!(result, Err(custom(BountyError::InvalidAuditorAccount)));
+
+    let before = env.ledger.lamports(&recipient);
+    let auditor_before = env.ledger.lamports(&auditor);
+    env.approve(&bounty).unwrap();
+    assert_eq!(env.ledger.lamports(&recipient), before + SOL);
+    assert_eq!(env.ledger.lamports(&auditor), auditor_before);
+}
+
+#[test]
+fn payout_cannot_be_redirected_to_the_creator() {
+    let mut env = Env::new();
+    let bounty = env.create(create_args(SOL, 0)).unwrap();
+    env.submit(&bounty, "ipfs://report").unwrap();
+    let creator = env.creator;
+
+    assert_eq!(
+        env.reassign_payout(&bounty, creator),
+        Err(custom(BountyError::InvalidPayoutRecipient))
+    );
+}
@@ -0,0 +1,59 @@
+mod common;
+
+use ::audit_bounty::*;
+use common::*;
+
+#[test]
+fn recovery_authority_reclaims_to_the_committed_destination() {
+    let mut env = Env::new();
+    let authority = env.ledger.wallet();
+    let destination = env.ledger.wallet();
+    let bounty = env
+        .create(instruction::CreateBounty {
+            recovery_authority: Some(authority),
+            recovery_destination: Some(destination),
+            ..create_args(SOL, 0)
+        })
+        .unwrap();
+
+    let creator_before = env.ledger.lamports(&env.creator);
+    let before = env.ledger.lamports(&destination);
+    env.recover(&bounty, authority).unwrap();
+
+    assert_eq!(env.ledger.lamports(&destination), before + SOL);
+    assert_eq!(env.ledger.lamports(&env.creator), creator_before);
+    assert!(!env.ledger.exists(&escrow_address(&bounty)));
+    assert!(env.bounty(&bounty).status == BountyStatus::Cancelled);
+    assert_eq!(env.metrics().total_refunded, SOL);
+}
+
+#[test]
+fn creator_cannot_use_recovery() {
+    let mut env = Env::new();
+    let authority = env.ledger.wallet();
+    let destination = env.ledger.wallet();
+    let bounty = env
+        .create(instruction::CreateBounty {
+            recovery_authority: Some(authority),
+            recovery_destination: Some(destination),
+            ..create_args(SOL, 0)
+        })
+        .unwrap();
+
+    let creator = env.creator;
+    assert_eq!(
+        env.recover(&bounty, creator),
+        Err(custom(BountyError::OnlyRecoveryAuthorityCanPerform))
+    );
+}
+
+#[test]
+fn recovery_needs_both_authority_and_destination() {
+    let mut env = Env::new();
+    let authority = env.ledger.wallet();
+    let result = env.create(instruction::CreateBounty {
+        recovery_authority: Some(authority),
+        ..create_args(SOL, 0)
+    });
+    assert_eq!(result, Err(custom(BountyError::InvalidRecoveryConfig)));
+}
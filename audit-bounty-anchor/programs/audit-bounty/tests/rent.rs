@@ -0,0 +1,88 @@
+mod common;
+
+use ::audit_bounty::*;
+use common::*;
+
+#[test]
+fn acceptance_rent_returns_to_the_auditor() {
+    let mut env = Env::new();
+    let bounty = env
+        .create(instruction::CreateBounty {
+            required_terms_hash: Some(hash(7)),
+            ..create_args(SOL, 0)
+        })
+        .unwrap();
+    let auditor = env.auditor;
+    env.accept_terms(&bounty, hash(7)).unwrap();
+    env.submit(&bounty, "ipfs://report").unwrap();
+
+    assert_eq!(env.close_acceptance(&bounty, auditor), Err(custom(BountyError::BountyNotSettled)));
+
+    env.approve(&bounty).unwrap();
+    let auditor_before = env.ledger.lamports(&auditor);
+    let creator_before = env.ledger.lamports(&env.creator);
+    env.close_acceptance(&bounty, auditor).unwrap();
+
+    assert!(!env.ledger.exists(&acceptance_address(&bounty, &auditor)));
+    assert_eq!(env.ledger.lamports(&auditor), auditor_before + rent_exempt(Acceptance::space()));
+    assert_eq!(env.ledger.lamports(&env.creator), creator_before);
+}
+
+#[test]
+fn log_and_approval_rent_returns_to_the_creator() {
+    let mut env = Env::new();
+    let approvers = [env.ledger.wallet(), env.ledger.wallet()];
+    let bounty = env
+        .create_with_log(
+            instruction::CreateBounty {
+                approvers: approvers.to_vec(),
+                approval_threshold: 2,
+                ..create_args(SOL, 0)
+            },
+            true,
+        )
+        .unwrap();
+
+    assert_eq!(env.close_bounty_accounts(&bounty), Err(custom(BountyError::BountyNotSettled)));
+
+    env.cancel_with(&bounty, &approvers).unwrap();
+    let creator_before = env.ledger.lamports(&env.creator);
+    let auditor_before = env.ledger.lamports(&env.auditor);
+    env.close_bounty_accounts(&bounty).unwrap();
+
+    assert!(!env.ledger.exists(&approval_address(&bounty)));
+    assert!(!env.ledger.exists(&event_log_address(&bounty)));
+    assert_eq!(
+        env.ledger.lamports(&env.creator),
+        creator_before + rent_exempt(ApprovalState::space()) + rent_exempt(EventLog::space())
+    );
+    assert_eq!(env.ledger.lamports(&env.auditor), auditor_before);
+
+    // The bounty stops expecting a log once it is gone
+    assert!(!env.bounty(&bounty).event_log_enabled);
+}
+
+#[test]
+fn unclaimed_holdback_keeps_the_log_open() {
+    let mut env = Env::new();
+    let release_at = time() + 60;
+    let bounty = env
+        .create_with_log(
+            instruction::CreateBounty {
+                holdback_bps: 2_500,
+                holdback_release_at: release_at,
+                ..create_args(4 * SOL, 0)
+            },
+            true,
+        )
+        .unwrap();
+    env.submit(&bounty, "ipfs://report").unwrap();
+    env.approve(&bounty).unwrap();
+
+    assert_eq!(env.close_bounty_accounts(&bounty), Err(custom(BountyError::BountyNotSettled)));
+
+    set_time(release_at);
+    env.claim_holdback(&bounty).unwrap();
+    env.close_bounty_accounts(&bounty).unwrap();
+    assert!(!env.ledger.exists(&event_log_address(&bounty)));
+}
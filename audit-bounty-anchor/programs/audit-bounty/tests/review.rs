@@ -0,0 +1,28 @@
+mod common;
+
+use ::audit_bounty::*;
+use common::*;
+
+#[test]
+fn review_is_recorded_and_logged() {
+    let mut env = Env::new();
+    let bounty = env.create_with_log(create_args(SOL, 0), true).unwrap();
+    env.submit(&bounty, "ipfs://report").unwrap();
+
+    advance(60);
+    env.mark_report_reviewed(&bounty).unwrap();
+    assert_eq!(env.bounty(&bounty).reviewed_at, Some(time()));
+
+    let event = env.last_event(&bounty);
+    assert!(event.kind == EventKind::Reviewed);
+    assert_eq!(event.actor, env.creator);
+    assert_eq!(event.amount, 0);
+}
+
+#[test]
+fn review_needs_a_submitted_report() {
+    let mut env = Env::new();
+    let bounty = env.create(create_args(SOL, 0)).unwrap();
+
+    assert_eq!(env.mark_report_reviewed(&bounty), Err(custom(BountyError::BountyNotInReview)));
+}
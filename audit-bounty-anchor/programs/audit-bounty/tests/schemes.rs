@@ -0,0 +1,33 @@
+mod common;
+
+use ::audit_bounty::*;
+use common::*;
+
+fn ipfs_only(env: &mut Env) -> anchor_lang::prelude::Pubkey {
+    env.create(instruction::CreateBounty {
+        allowed_schemes: vec!["ipfs://".to_string()],
+        ..create_args(SOL, 0)
+    })
+    .unwrap()
+}
+
+#[test]
+fn allowed_scheme_is_accepted() {
+    let mut env = Env::new();
+    let bounty = ipfs_only(&mut env);
+
+    env.submit(&bounty, "ipfs://bafy-report").unwrap();
+    assert_eq!(env.bounty(&bounty).report_uri.as_deref(), Some("ipfs://bafy-report"));
+}
+
+#[test]
+fn disallowed_scheme_is_rejected() {
+    let mut env = Env::new();
+    let bounty = ipfs_only(&mut env);
+
+    assert_eq!(
+        env.submit(&bounty, "https://gateway.example/report"),
+        Err(custom(BountyError::ReportSchemeNotAllowed))
+    );
+    assert!(env.bounty(&bounty).status == BountyStatus::Open);
+}
@@ -0,0 +1,112 @@
+mod common;
+
+use ::audit_bounty::*;
+use common::*;
+
+#[test]
+fn report_hash_is_recorded_and_fixed() {
+    let mut env = Env::new();
+    let bounty = env.create(create_args(SOL, 0)).unwrap();
+    let auditor = env.auditor;
+
+    env.submit_as(&bounty, auditor, "ipfs://report", hash(0), hash(9)).unwrap();
+    assert_eq!(env.bounty(&bounty).report_hash, Some(hash(9)));
+
+    // A second submission cannot replace it while the report is under review
+    assert_eq!(
+        env.submit_as(&bounty, auditor, "ipfs://report", hash(0), hash(10)),
+        Err(custom(BountyError::BountyNotOpen))
+    );
+    assert_eq!(env.bounty(&bounty).report_hash, Some(hash(9)));
+
+    env.reject(&bounty).unwrap();
+    assert_eq!(env.bounty(&bounty).report_hash, None);
+}
+
+#[test]
+fn zero_report_hash_is_rejected() {
+    let mut env = Env::new();
+    let bounty = env.create(create_args(SOL, 0)).unwrap();
+    let auditor = env.auditor;
+
+    assert_eq!(
+        env.submit_as(&bounty, auditor, "ipfs://report", hash(0), [0; 32]),
+        Err(custom(BountyError::InvalidReportHash))
+    );
+}
+
+#[test]
+fn matching_template_hash_is_accepted() {
+    let mut env = Env::new();
+    let bounty = env
+        .create(instruction::CreateBounty {
+            required_template_hash: Some(hash(5)),
+            ..create_args(SOL, 0)
+        })
+        .unwrap();
+    let auditor = env.auditor;
+
+    env.submit_as(&bounty, auditor, "ipfs://report", hash(5), hash(1)).unwrap();
+    assert_eq!(env.bounty(&bounty).report_template_hash, Some(hash(5)));
+}
+
+#[test]
+fn mismatching_template_hash_is_rejected() {
+    let mut env = Env::new();
+    let bounty = env
+        .create(instruction::CreateBounty {
+            required_template_hash: Some(hash(5)),
+            ..create_args(SOL, 0)
+        })
+        .unwrap();
+    let auditor = env.auditor;
+
+    assert_eq!(
+        env.submit_as(&bounty, auditor, "ipfs://report", hash(6), hash(1)),
+        Err(custom(BountyError::TemplateMismatch))
+    );
+    assert!(env.bounty(&bounty).status == BountyStatus::Open);
+}
+
+#[test]
+fn creator_cannot_submit_to_their_own_bounty() {
+    let mut env = Env::new();
+    let bounty = env.create(create_args(SOL, 0)).unwrap();
+    let creator = env.creator;
+
+    assert_eq!(
+        env.submit_as(&bounty, creator, "ipfs://report", hash(0), hash(1)),
+        Err(custom(BountyError::CannotAuditOwnBounty))
+    );
+    assert!(env.bounty(&bounty).status == BountyStatus::Open);
+    assert_eq!(env.bounty(&bounty).auditor, None);
+
+    // With no report there is nothing to approve to themselves
+    assert_eq!(env.approve(&bounty), Err(custom(BountyError::BountyNotInReview)));
+}
+
+#[test]
+fn submissions_wait_for_the_start_time() {
+    let mut env = Env::new();
+    let start = time() + 3_600;
+    let bounty = env
+        .create(instruction::CreateBounty { submission_start: start, ..create_args(SOL, 0) })
+        .unwrap();
+
+    set_time(start - 1);
+    assert_eq!(env.submit(&bounty, "ipfs://report"), Err(custom(BountyError::SubmissionNotYetOpen)));
+
+    set_time(start);
+    env.submit(&bounty, "ipfs://report").unwrap();
+    assert!(env.bounty(&bounty).status == BountyStatus::Submitted);
+}
+
+#[test]
+fn past_start_time_accepts_submissions_immediately() {
+    let mut env = Env::new();
+    let bounty = env
+        .create(instruction::CreateBounty { submission_start: time() - 1, ..create_args(SOL, 0) })
+        .unwrap();
+
+    env.submit(&bounty, "ipfs://report").unwrap();
+}
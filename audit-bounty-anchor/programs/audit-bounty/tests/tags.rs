@@ -0,0 +1,29 @@
+mod common;
+
+use ::audit_bounty::*;
+use common::*;
+
+#[test]
+fn tags_read_back_at_the_filter_offset() {
+    let mut env = Env::new();
+    let tags = vec![BountyCategory::SmartContract as u8, BountyCategory::Defi as u8];
+    let bounty = env
+        .create(instruction::CreateBounty { tags: tags.clone(), ..create_args(SOL, 0) })
+        .unwrap();
+
+    assert_eq!(env.bounty(&bounty).tags, tags);
+
+    let data = env.ledger.data(&bounty);
+    assert_eq!(&data[Bounty::TAGS_LEN_OFFSET..Bounty::TAGS_OFFSET], &2u32.to_le_bytes());
+    assert_eq!(&data[Bounty::TAGS_OFFSET..Bounty::TAGS_OFFSET + 2], &tags[..]);
+}
+
+#[test]
+fn unknown_category_is_rejected() {
+    let mut env = Env::new();
+    let result = env.create(instruction::CreateBounty {
+        tags: vec![BountyCategory::COUNT],
+        ..create_args(SOL, 0)
+    });
+    assert_eq!(result, Err(custom(BountyError::InvalidTags)));
+}
@@ -0,0 +1,48 @@
+mod common;
+
+use ::audit_bounty::*;
+use common::*;
+
+fn with_terms(env: &mut Env, terms: [u8; 32]) -> anchor_lang::prelude::Pubkey {
+    env.create(instruction::CreateBounty {
+        required_terms_hash: Some(terms),
+        ..create_args(SOL, 0)
+    })
+    .unwrap()
+}
+
+#[test]
+fn submission_requires_accepted_terms() {
+    let mut env = Env::new();
+    let bounty = with_terms(&mut env, hash(7));
+
+    assert_eq!(env.submit(&bounty, "ipfs://report"), Err(custom(BountyError::TermsNotAccepted)));
+}
+
+#[test]
+fn submission_allowed_after_accepting_the_terms() {
+    let mut env = Env::new();
+    let bounty = with_terms(&mut env, hash(7));
+
+    env.accept_terms(&bounty, hash(7)).unwrap();
+    env.submit(&bounty, "ipfs://report").unwrap();
+}
+
+#[test]
+fn accepting_other_terms_is_rejected() {
+    let mut env = Env::new();
+    let bounty = with_terms(&mut env, hash(7));
+
+    assert_eq!(env.accept_terms(&bounty, hash(8)), Err(custom(BountyError::TermsMismatch)));
+    assert!(!env.ledger.exists(&acceptance_address(&bounty, &env.auditor)));
+    assert_eq!(env.submit(&bounty, "ipfs://report"), Err(custom(BountyError::TermsNotAccepted)));
+}
+
+#[test]
+fn bounty_without_terms_has_nothing_to_accept() {
+    let mut env = Env::new();
+    let bounty = env.create(create_args(SOL, 0)).unwrap();
+
+    assert_eq!(env.accept_terms(&bounty, hash(7)), Err(custom(BountyError::TermsMismatch)));
+    env.submit(&bounty, "ipfs://report").unwrap();
+}
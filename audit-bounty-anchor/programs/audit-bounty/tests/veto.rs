@@ -0,0 +1,51 @@
+mod common;
+
+use ::audit_bounty::*;
+use common::*;
+
+fn with_veto(env: &mut Env) -> (anchor_lang::prelude::Pubkey, i64) {
+    let veto_until = time() + AUTO_RELEASE_DEADLINE - 60 * 60;
+    let bounty = env
+        .create(instruction::CreateBounty {
+            creator_veto_until: veto_until,
+            ..create_args(SOL, 0)
+        })
+        .unwrap();
+    (bounty, veto_until)
+}
+
+#[test]
+fn last_second_reject_prevents_auto_release() {
+    let mut env = Env::new();
+    let (bounty, veto_until) = with_veto(&mut env);
+    env.submit(&bounty, "ipfs://report").unwrap();
+
+    set_time(veto_until - 1);
+    env.reject(&bounty).unwrap();
+    env.submit(&bounty, "ipfs://report-v2").unwrap();
+    assert!(env.bounty(&bounty).auto_release_vetoed);
+
+    set_time(env.bounty(&bounty).auto_release_at());
+    assert_eq!(env.auto_release(&bounty), Err(custom(BountyError::AutoReleaseVetoed)));
+    assert_eq!(env.ledger.lamports(&escrow_address(&bounty)), SOL);
+
+    // The creator can still approve explicitly
+    let before = env.ledger.lamports(&env.auditor);
+    env.approve(&bounty).unwrap();
+    assert_eq!(env.ledger.lamports(&env.auditor), before + SOL);
+}
+
+#[test]
+fn reject_after_the_veto_window_does_not_veto() {
+    let mut env = Env::new();
+    let (bounty, veto_until) = with_veto(&mut env);
+    env.submit(&bounty, "ipfs://report").unwrap();
+
+    set_time(veto_until);
+    env.reject(&bounty).unwrap();
+    env.submit(&bounty, "ipfs://report-v2").unwrap();
+    assert!(!env.bounty(&bounty).auto_release_vetoed);
+
+    set_time(env.bounty(&bounty).auto_release_at());
+    env.auto_release(&bounty).unwrap();
+}